@@ -93,7 +93,11 @@ impl TiledPhysicsBackend for MyCustomPhysicsBackend {
             name: format!("Custom[{}]", object_data.name),
             entity: commands.spawn(MyCustomPhysicsComponent(color)).id(),
             position: pos,
-            rotation: -object_data.rotation,
+            rotation: match collider_source.ty {
+                // Objects entities are already rotated
+                TiledColliderSourceType::Object { .. } => 0.,
+                TiledColliderSourceType::Tile { .. } => -object_data.rotation,
+            },
         })
     }
 }
@@ -118,7 +118,11 @@ impl TiledPhysicsBackend for MyCustomPhysicsBackend {
             name: format!("Custom[{}]", object_data.name),
             entity: commands.spawn(MyCustomPhysicsComponent).id(),
             position: pos,
-            rotation: -object_data.rotation,
+            rotation: match collider_source.ty {
+                // Objects entities are already rotated
+                TiledColliderSourceType::Object { .. } => 0.,
+                TiledColliderSourceType::Tile { .. } => -object_data.rotation,
+            },
         })
     }
 }
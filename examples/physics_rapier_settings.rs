@@ -64,11 +64,11 @@ fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 layer_positioning: LayerPositioning::Centered,
                 ..default()
             });
-            c.insert(TiledPhysicsSettings::<TiledPhysicsRapierBackend> {
-                objects_layer_filter: ObjectNames::None,
-                tiles_objects_filter: ObjectNames::Names(vec!["collision".to_string()]),
-                ..default()
-            });
+            c.insert(
+                TiledPhysicsSettings::<TiledPhysicsRapierBackend>::default()
+                    .with_objects_layer_filter(ObjectNames::None)
+                    .with_tiles_objects_filter(ObjectNames::Names(vec!["collision".to_string()])),
+            );
         },
     ));
     mgr.add_map(helper::assets::MapInfos::new(
@@ -80,11 +80,11 @@ fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 layer_positioning: LayerPositioning::Centered,
                 ..default()
             });
-            c.insert(TiledPhysicsSettings::<TiledPhysicsRapierBackend> {
-                objects_layer_filter: ObjectNames::All,
-                tiles_objects_filter: ObjectNames::None,
-                ..default()
-            });
+            c.insert(
+                TiledPhysicsSettings::<TiledPhysicsRapierBackend>::default()
+                    .with_objects_layer_filter(ObjectNames::All)
+                    .with_tiles_objects_filter(ObjectNames::None),
+            );
         },
     ));
     commands.insert_resource(mgr);
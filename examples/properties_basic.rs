@@ -25,6 +25,7 @@ fn main() {
             // Note: if you set this setting to `None`
             // properties will still be translated
             tiled_types_export_file: Some(path),
+            ..default()
         }))
         // We need to register all the types we want to use
         .register_type::<BiomeInfos>()
@@ -0,0 +1,109 @@
+//! This module contains utilities to control tiles animations at runtime.
+//!
+//! Tiles animations are handled by `bevy_ecs_tilemap`, which computes the frame to display
+//! from the [Time] resource, ie. the [Virtual] clock when running in [Update].
+//! Pausing or scaling tiles animations is therefore done by pausing or scaling [`Time<Virtual>`].
+//!
+//! Note that it will also affect everything else relying on the [Virtual] clock,
+//! for instance physics or your own gameplay systems.
+
+use bevy::prelude::*;
+
+/// `Plugin` to control tiles animations at runtime.
+///
+/// Once added, [TiledAnimationState] and [TiledAnimationTimeScale] resources
+/// are applied to [`Time<Virtual>`] whenever they change.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// App::new()
+///     .add_plugins(TiledAnimationPlugin)
+///     .add_systems(Update, toggle_pause);
+///
+/// fn toggle_pause(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<TiledAnimationState>) {
+///     if keys.just_pressed(KeyCode::Escape) {
+///         *state = match *state {
+///             TiledAnimationState::Playing => TiledAnimationState::Paused,
+///             TiledAnimationState::Paused => TiledAnimationState::Playing,
+///         };
+///     }
+/// }
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct TiledAnimationPlugin;
+
+impl Plugin for TiledAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TiledAnimationState>()
+            .init_resource::<TiledAnimationTimeScale>()
+            .add_systems(PreUpdate, update_animation_time);
+    }
+}
+
+/// [Resource] to pause and resume tiles animations.
+///
+/// When paused, animated tiles hold their current frame.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiledAnimationState {
+    /// Tiles animations are playing.
+    #[default]
+    Playing,
+    /// Tiles animations are paused on their current frame.
+    Paused,
+}
+
+/// [Resource] to scale tiles animations speed.
+///
+/// A value of `1.0` plays animations at their normal speed,
+/// `0.5` plays them twice as slow.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct TiledAnimationTimeScale(pub f32);
+
+impl Default for TiledAnimationTimeScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+fn update_animation_time(
+    state: Res<TiledAnimationState>,
+    time_scale: Res<TiledAnimationTimeScale>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if state.is_changed() {
+        match *state {
+            TiledAnimationState::Playing => time.unpause(),
+            TiledAnimationState::Paused => time.pause(),
+        }
+    }
+    if time_scale.is_changed() {
+        time.set_relative_speed(time_scale.0.max(0.));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_and_scale_virtual_time() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TiledAnimationPlugin));
+        app.update();
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+
+        *app.world_mut().resource_mut::<TiledAnimationState>() = TiledAnimationState::Paused;
+        app.world_mut().resource_mut::<TiledAnimationTimeScale>().0 = 0.5;
+        app.update();
+        let time = app.world().resource::<Time<Virtual>>();
+        assert!(time.is_paused());
+        assert_eq!(time.relative_speed(), 0.5);
+
+        *app.world_mut().resource_mut::<TiledAnimationState>() = TiledAnimationState::Playing;
+        app.update();
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    }
+}
@@ -3,23 +3,33 @@
 use std::io::{Cursor, Error as IoError, ErrorKind, Read};
 #[cfg(feature = "user_properties")]
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use bevy::reflect::TypeRegistry;
 #[cfg(feature = "user_properties")]
 use bevy::reflect::TypeRegistryArc;
 
 #[cfg(feature = "user_properties")]
-use crate::properties::load::DeserializedMapProperties;
+use crate::properties::{
+    import::{read_project_types, validate_map_types},
+    load::DeserializedMapProperties,
+    TiledColorFormat,
+};
+#[cfg(feature = "user_properties")]
+use bevy::utils::HashSet;
 
 use bevy::{
-    asset::{io::Reader, AssetLoader, AssetPath, LoadContext},
+    asset::{io::Reader, AssetLoader, AssetPath, AssetSourceId, LoadContext},
+    math::bounding::Aabb2d,
     prelude::*,
     utils::HashMap,
 };
 
 use bevy_ecs_tilemap::prelude::*;
 
+use crate::prelude::*;
+
 /// Tiled map `Asset`.
 ///
 /// `Asset` holding Tiled map informations.
@@ -27,8 +37,17 @@ use bevy_ecs_tilemap::prelude::*;
 pub struct TiledMap {
     pub map: tiled::Map,
 
+    /// Map parallax origin, in Tiled coordinates.
+    ///
+    /// It is the camera position for which parallax layers are at their authored position.
+    pub parallax_origin: Vec2,
+
     pub tilemap_textures: HashMap<usize, TilemapTexture>,
 
+    /// [LayerPositioning] read from the map custom property named after
+    /// [TiledMapPluginConfig::layer_positioning_property](crate::TiledMapPluginConfig::layer_positioning_property), if any.
+    pub layer_positioning: Option<LayerPositioning>,
+
     #[cfg(feature = "user_properties")]
     pub(crate) properties: DeserializedMapProperties,
 
@@ -37,83 +56,576 @@ pub struct TiledMap {
     pub tile_image_offsets: HashMap<(usize, tiled::TileId), u32>,
 }
 
-struct BytesResourceReader<'a, 'b> {
-    bytes: Arc<[u8]>,
-    context: &'a mut LoadContext<'b>,
+/// Metadata about a tileset used by a [TiledMap].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TiledTilesetInfos {
+    /// Tileset name.
+    pub name: String,
+    /// Number of tiles columns in the tileset image.
+    ///
+    /// For image collection tilesets, this is 0.
+    pub columns: u32,
+    /// Number of tiles rows in the tileset image.
+    ///
+    /// For image collection tilesets, this is 0.
+    pub rows: u32,
+    /// Number of tiles in the tileset.
+    pub tile_count: u32,
+    /// Maximum width of tiles in this tileset, in pixels.
+    pub tile_width: u32,
+    /// Maximum height of tiles in this tileset, in pixels.
+    pub tile_height: u32,
+    /// Spacing between tiles in the tileset image, in pixels.
+    pub spacing: u32,
+    /// Margin around tiles in the tileset image, in pixels.
+    pub margin: u32,
 }
-impl<'a, 'b> BytesResourceReader<'a, 'b> {
-    fn new(bytes: &'a [u8], context: &'a mut LoadContext<'b>) -> Self {
+
+impl From<&tiled::Tileset> for TiledTilesetInfos {
+    fn from(tileset: &tiled::Tileset) -> Self {
         Self {
-            bytes: Arc::from(bytes),
-            context,
+            name: tileset.name.clone(),
+            columns: tileset.columns,
+            rows: if tileset.columns > 0 {
+                tileset.tilecount.div_ceil(tileset.columns)
+            } else {
+                0
+            },
+            tile_count: tileset.tilecount,
+            tile_width: tileset.tile_width,
+            tile_height: tileset.tile_height,
+            spacing: tileset.spacing,
+            margin: tileset.margin,
         }
     }
 }
 
-impl<'a> tiled::ResourceReader for BytesResourceReader<'a, '_> {
-    type Resource = Box<dyn Read + 'a>;
-    type Error = IoError;
+/// Compute the top-left and bottom-right chunks coordinates of an infinite tiles layer.
+pub(crate) fn infinite_layer_bounds(
+    infinite_layer: &tiled::InfiniteTileLayer,
+) -> ((i32, i32), (i32, i32)) {
+    let topleft = infinite_layer
+        .chunks()
+        .fold((999999, 999999), |acc, (pos, _)| {
+            (acc.0.min(pos.0), acc.1.min(pos.1))
+        });
+    let bottomright = infinite_layer.chunks().fold(topleft, |acc, (pos, _)| {
+        (acc.0.max(pos.0), acc.1.max(pos.1))
+    });
+    (topleft, bottomright)
+}
 
-    fn read_from(&mut self, path: &Path) -> std::result::Result<Self::Resource, Self::Error> {
-        if let Some(extension) = path.extension() {
-            if extension == "tsx" {
-                let future = self.context.read_asset_bytes(path.to_path_buf());
-                let data = futures_lite::future::block_on(future)
-                    .map_err(|err| IoError::new(ErrorKind::NotFound, err))?;
-                return Ok(Box::new(Cursor::new(data)));
-            }
+impl TiledMap {
+    /// Write this map to a `.tmx` string.
+    ///
+    /// `map_path` is the path where the map will be saved, used to write relative paths.
+    /// Like the map own paths, it is relative to the assets directory.
+    /// Note that export is lossy, see [crate::writer] for more details.
+    ///
+    /// Only the asset content is written: maps edited at runtime cannot be saved, since
+    /// changes made to the spawned map entities, such as tiles changed through `bevy_ecs_tilemap`,
+    /// are not exported.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// fn save(maps: Res<Assets<TiledMap>>, q_maps: Query<&TiledMapHandle>) {
+    ///     for handle in q_maps.iter() {
+    ///         if let Some(map) = maps.get(&handle.0) {
+    ///             let path = std::path::Path::new("saves/saved.tmx");
+    ///             let content = map.to_tmx_string(path);
+    ///             std::fs::write(std::path::Path::new("assets").join(path), content).unwrap();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn to_tmx_string(&self, map_path: &Path) -> String {
+        crate::writer::write_tmx(&self.map, map_path)
+    }
+
+    /// Get metadata about the tileset at provided index.
+    ///
+    /// Returns `None` if there is no tileset at this index.
+    pub fn tileset_infos(&self, tileset_index: usize) -> Option<TiledTilesetInfos> {
+        self.map
+            .tilesets()
+            .get(tileset_index)
+            .map(|tileset| TiledTilesetInfos::from(tileset.as_ref()))
+    }
+
+    /// Get the probability of a tile, as used by Tiled random mode.
+    ///
+    /// Returns `None` if there is no such tile.
+    pub fn tile_probability(&self, tileset_index: usize, tile_id: tiled::TileId) -> Option<f32> {
+        self.map
+            .tilesets()
+            .get(tileset_index)
+            .and_then(|tileset| tileset.get_tile(tile_id))
+            .map(|tile| tile.probability)
+    }
+
+    /// Get the Wang sets (ie. terrain sets) of the tileset at provided index.
+    ///
+    /// Returns `None` if there is no tileset at this index.
+    pub fn wang_sets(&self, tileset_index: usize) -> Option<&[tiled::WangSet]> {
+        self.map
+            .tilesets()
+            .get(tileset_index)
+            .map(|tileset| tileset.wang_sets.as_slice())
+    }
+
+    /// Iterate over the Wang IDs of a tile, for each Wang set of its tileset which contains this tile.
+    ///
+    /// Each item is the index of the Wang set along with the tile Wang ID: it contains the
+    /// Wang color index (starting at 1, 0 meaning no color) of each edge and corner of the tile,
+    /// clockwise, starting from the top edge.
+    pub fn tile_wang_ids(
+        &self,
+        tileset_index: usize,
+        tile_id: tiled::TileId,
+    ) -> impl Iterator<Item = (usize, [u8; 8])> + '_ {
+        self.wang_sets(tileset_index)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, wang_set)| {
+                wang_set
+                    .wang_tiles
+                    .get(&tile_id)
+                    .map(|wang_tile| (index, wang_tile.wang_id.0))
+            })
+    }
+
+    /// Get the animation frames of a tile, as authored in Tiled.
+    ///
+    /// Returns `None` if there is no such tile or if the tile is not animated.
+    pub fn tile_animation(
+        &self,
+        tileset_index: usize,
+        tile_id: tiled::TileId,
+    ) -> Option<Vec<tiled::Frame>> {
+        self.map
+            .tilesets()
+            .get(tileset_index)
+            .and_then(|tileset| tileset.get_tile(tile_id))
+            .and_then(|tile| tile.animation.clone())
+    }
+
+    /// Get metadata about the first tileset with provided name.
+    ///
+    /// Returns its index along with its metadata, or `None` if no tileset has this name.
+    pub fn tileset_infos_by_name(&self, name: &str) -> Option<(usize, TiledTilesetInfos)> {
+        self.tilesets_infos().find(|(_, infos)| infos.name == name)
+    }
+
+    /// Iterate over metadata about all tilesets used by this map, along with their index.
+    pub fn tilesets_infos(&self) -> impl Iterator<Item = (usize, TiledTilesetInfos)> + '_ {
+        self.map
+            .tilesets()
+            .iter()
+            .enumerate()
+            .map(|(index, tileset)| (index, TiledTilesetInfos::from(tileset.as_ref())))
+    }
+
+    /// Convert a [TilePos] of an infinite tiles layer to the coordinates of the Tiled chunk containing it.
+    ///
+    /// Since `bevy_ecs_tilemap` does not support negative tile positions, tiles of infinite layers
+    /// are shifted so that the top-left chunk of the layer is at (0, 0).
+    /// Returns `None` if there is no infinite tiles layer with provided ID or if the position is outside the layer.
+    pub fn tile_pos_to_chunk_coord(&self, layer_id: u32, tile_pos: TilePos) -> Option<(i32, i32)> {
+        let (topleft, bottomright) = self.infinite_layer_bounds(layer_id)?;
+        let height = (bottomright.1 - topleft.1 + 1) as u32 * tiled::ChunkData::HEIGHT;
+        let width = (bottomright.0 - topleft.0 + 1) as u32 * tiled::ChunkData::WIDTH;
+        if tile_pos.x >= width || tile_pos.y >= height {
+            return None;
         }
-        Ok(Box::new(Cursor::new(self.bytes.clone())))
+        // Invert y to match Tiled coordinates
+        let tile_y = height - 1 - tile_pos.y;
+        Some((
+            (tile_pos.x / tiled::ChunkData::WIDTH) as i32 + topleft.0,
+            (tile_y / tiled::ChunkData::HEIGHT) as i32 + topleft.1,
+        ))
     }
-}
 
-pub(crate) struct TiledLoader {
-    #[cfg(feature = "user_properties")]
-    pub registry: TypeRegistryArc,
-}
+    /// Convert Tiled chunk coordinates of an infinite tiles layer to the [TilePos] of the chunk top-left tile.
+    ///
+    /// See [TiledMap::tile_pos_to_chunk_coord] for more details.
+    /// Returns `None` if there is no infinite tiles layer with provided ID or if the chunk is outside the layer.
+    pub fn chunk_coord_to_tile_origin(&self, layer_id: u32, chunk: (i32, i32)) -> Option<TilePos> {
+        let (topleft, bottomright) = self.infinite_layer_bounds(layer_id)?;
+        if chunk.0 < topleft.0
+            || chunk.1 < topleft.1
+            || chunk.0 > bottomright.0
+            || chunk.1 > bottomright.1
+        {
+            return None;
+        }
+        let height = (bottomright.1 - topleft.1 + 1) as u32 * tiled::ChunkData::HEIGHT;
+        Some(TilePos {
+            x: (chunk.0 - topleft.0) as u32 * tiled::ChunkData::WIDTH,
+            y: height - 1 - (chunk.1 - topleft.1) as u32 * tiled::ChunkData::HEIGHT,
+        })
+    }
 
-impl FromWorld for TiledLoader {
-    fn from_world(_world: &mut World) -> Self {
-        Self {
-            #[cfg(feature = "user_properties")]
-            registry: _world.resource::<AppTypeRegistry>().0.clone(),
+    fn infinite_layer_bounds(&self, layer_id: u32) -> Option<((i32, i32), (i32, i32))> {
+        self.map
+            .layers()
+            .find(|layer| layer.id() == layer_id)
+            .and_then(|layer| match layer.as_tile_layer() {
+                Some(tiled::TileLayer::Infinite(infinite_layer)) => {
+                    Some(infinite_layer_bounds(&infinite_layer))
+                }
+                _ => None,
+            })
+    }
+
+    /// Get the [LayerPositioning] applied to this map.
+    ///
+    /// [TiledMapSettings::layer_positioning] is used if it was explicitly set, ie. if it is not
+    /// [LayerPositioning::default()]. Otherwise, the map own [TiledMap::layer_positioning] is used.
+    pub fn layer_positioning(&self, settings: &TiledMapSettings) -> LayerPositioning {
+        if settings.layer_positioning != LayerPositioning::default() {
+            return settings.layer_positioning;
         }
+        self.layer_positioning.unwrap_or(settings.layer_positioning)
     }
-}
 
-/// [TiledMap] loading error.
-#[derive(Debug, thiserror::Error)]
-pub enum TiledAssetLoaderError {
-    /// An [IO](std::io) Error
-    #[error("Could not load Tiled file: {0}")]
-    Io(#[from] std::io::Error),
-}
+    /// Get the [Transform] applied to all layers of this map according to [LayerPositioning].
+    ///
+    /// It does not include the layers own offset or Z-offset.
+    /// See [TiledMap::layer_positioning] for the [LayerPositioning] which is actually used.
+    pub fn positioning_transform(&self, settings: &TiledMapSettings) -> Transform {
+        match self.layer_positioning(settings) {
+            LayerPositioning::TiledOffset => Transform::IDENTITY,
+            LayerPositioning::Centered => get_tilemap_center_transform(
+                &get_map_size(&self.map),
+                &get_grid_size(&self.map),
+                &get_map_type(&self.map),
+                0.,
+            ),
+        }
+    }
 
-impl AssetLoader for TiledLoader {
-    type Asset = TiledMap;
-    type Settings = ();
-    type Error = TiledAssetLoaderError;
+    /// Get the map bounding [Rect], in the map referential.
+    ///
+    /// Returned [Rect] does not take into account the [LayerPositioning] transform
+    /// nor the layers own offset.
+    /// For infinite maps, it only covers the map size declared in Tiled.
+    pub fn rect(&self) -> Rect {
+        let map_type = get_map_type(&self.map);
+        let map_size = get_map_size(&self.map);
+        let grid_size = get_grid_size(&self.map);
+        let half_tile = Vec2::new(grid_size.x / 2., grid_size.y / 2.);
 
-    async fn load(
+        // Tilemaps are offset by half a tile when spawned
+        let max_x = map_size.x.saturating_sub(1);
+        let max_y = map_size.y.saturating_sub(1);
+        [(0, 0), (max_x, 0), (0, max_y), (max_x, max_y)]
+            .into_iter()
+            .map(|(x, y)| TilePos::new(x, y).center_in_world(&grid_size, &map_type) + half_tile)
+            .map(|center| Rect::from_center_half_size(center, half_tile))
+            .reduce(|a, b| a.union(b))
+            .unwrap_or_default()
+    }
+
+    /// Get the map bounding [Aabb2d], in world space.
+    ///
+    /// `map_transform` and `settings` are the [GlobalTransform] and [TiledMapSettings]
+    /// of the map entity.
+    /// Can be used to clamp a camera so it stays inside the map.
+    pub fn world_aabb(
         &self,
-        reader: &mut dyn Reader,
-        _settings: &Self::Settings,
-        load_context: &mut LoadContext<'_>,
-    ) -> Result<Self::Asset, Self::Error> {
-        let mut bytes = Vec::new();
-        reader.read_to_end(&mut bytes).await?;
+        map_transform: &GlobalTransform,
+        settings: &TiledMapSettings,
+    ) -> Aabb2d {
+        let rect = self.rect();
+        let transform =
+            *map_transform * GlobalTransform::from(self.positioning_transform(settings));
+        let corners = [
+            rect.min,
+            Vec2::new(rect.max.x, rect.min.y),
+            Vec2::new(rect.min.x, rect.max.y),
+            rect.max,
+        ]
+        .map(|corner| transform.transform_point(corner.extend(0.)).truncate());
+        Aabb2d {
+            min: corners.into_iter().reduce(Vec2::min).unwrap(),
+            max: corners.into_iter().reduce(Vec2::max).unwrap(),
+        }
+    }
 
-        log::info!("Start loading map '{}'", load_context.path().display());
+    /// Convert a position in Tiled pixel coordinates to world space.
+    ///
+    /// `map_transform` and `settings` are the [GlobalTransform] and [TiledMapSettings]
+    /// of the map entity.
+    /// The position is converted the same way objects are placed, for both finite and infinite maps.
+    /// Layers own offset is not taken into account.
+    pub fn tiled_to_world(
+        &self,
+        tiled_position: Vec2,
+        map_transform: &GlobalTransform,
+        settings: &TiledMapSettings,
+    ) -> Vec2 {
+        let map_position = from_tiled_coords_to_bevy(
+            tiled_position,
+            &get_map_type(&self.map),
+            &get_map_size(&self.map),
+            &get_grid_size(&self.map),
+        );
+        (*map_transform * GlobalTransform::from(self.positioning_transform(settings)))
+            .transform_point(map_position.extend(0.))
+            .truncate()
+    }
+
+    /// Get the vertices of a Tiled [Object](tiled::Object), relative to its layer.
+    ///
+    /// Vertices take into account the object position and rotation and are expressed
+    /// in Bevy referential, the same way the object [Entity] is positioned relative to its layer.
+    /// See [object_shape_vertices] for more details.
+    pub fn object_vertices(&self, object: &tiled::ObjectData) -> Vec<Vec2> {
+        let map_type = get_map_type(&self.map);
+        let map_size = get_map_size(&self.map);
+        let grid_size = get_grid_size(&self.map);
+        // Tiled rotation is clockwise, which is a regular rotation when y-axis points down
+        let rotation = Mat2::from_angle(object.rotation.to_radians());
+        let origin = Vec2::new(object.x, object.y);
+        object_shape_vertices(&object.shape)
+            .into_iter()
+            .map(|vertex| {
+                from_tiled_coords_to_bevy(
+                    origin + rotation * vertex,
+                    &map_type,
+                    &map_size,
+                    &grid_size,
+                )
+            })
+            .collect()
+    }
+
+    /// Get the vertices of a Tiled [Object](tiled::Object), in world space.
+    ///
+    /// `layer_transform` is the [GlobalTransform] of the object layer [Entity].
+    /// Can be used to get the actual polygon of an object without the `physics` feature.
+    pub fn object_world_vertices(
+        &self,
+        object: &tiled::ObjectData,
+        layer_transform: &GlobalTransform,
+    ) -> Vec<Vec2> {
+        self.object_vertices(object)
+            .into_iter()
+            .map(|vertex| {
+                layer_transform
+                    .transform_point(vertex.extend(0.))
+                    .truncate()
+            })
+            .collect()
+    }
+
+    /// Get the polygons of all objects from an object layer, in world space.
+    ///
+    /// Only objects whose name matches `filter` and which are closed shapes (rectangles,
+    /// ellipses and polygons) are returned: polylines, points and texts are ignored.
+    /// `layer_transform` is the [GlobalTransform] of the object layer [Entity].
+    /// Can be used to build a navigation mesh from "walkable" objects for instance.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// fn walkable_areas(
+    ///     map_asset: Res<Assets<TiledMap>>,
+    ///     q_map: Query<(&TiledMapHandle, &TiledIdStorage)>,
+    ///     q_layers: Query<&GlobalTransform>,
+    /// ) {
+    ///     let (map_handle, storage) = q_map.single();
+    ///     let Some(tiled_map) = map_asset.get(&map_handle.0) else {
+    ///         return;
+    ///     };
+    ///     let Some(layer_transform) = storage
+    ///         .layer_by_name(tiled_map, "navigation")
+    ///         .and_then(|layer| q_layers.get(layer).ok())
+    ///     else {
+    ///         return;
+    ///     };
+    ///     let polygons = tiled_map.layer_polygons(
+    ///         "navigation",
+    ///         &ObjectNames::Names(vec![String::from("walkable")]),
+    ///         layer_transform,
+    ///     );
+    ///     info!("Found {} walkable areas", polygons.len());
+    /// }
+    /// ```
+    pub fn layer_polygons(
+        &self,
+        layer_name: &str,
+        filter: &ObjectNames,
+        layer_transform: &GlobalTransform,
+    ) -> Vec<Vec<Vec2>> {
+        let Some(object_layer) = self
+            .map
+            .layers()
+            .find(|layer| layer.name == layer_name)
+            .and_then(|layer| layer.as_object_layer())
+        else {
+            return vec![];
+        };
+        let filter = ObjectNameFilter::from(filter);
+        object_layer
+            .objects()
+            .filter(|object| filter.contains(&object.name))
+            .filter(|object| match &object.shape {
+                tiled::ObjectShape::Rect { .. } | tiled::ObjectShape::Ellipse { .. } => true,
+                tiled::ObjectShape::Polygon { points } => points.len() >= 3,
+                _ => false,
+            })
+            .map(|object| self.object_world_vertices(&object, layer_transform))
+            .collect()
+    }
+
+    /// Get the neighbors of a [TilePos] for this map.
+    ///
+    /// See [tile_neighbors] for more details.
+    pub fn tile_neighbors(
+        &self,
+        tile_pos: &TilePos,
+        include_diagonals: bool,
+    ) -> impl Iterator<Item = TilePos> {
+        tile_neighbors(
+            tile_pos,
+            &get_map_type(&self.map),
+            &get_map_size(&self.map),
+            include_diagonals,
+        )
+    }
+
+    /// Get the [TilePos] located at given world position.
+    ///
+    /// `map_transform` and `settings` are the [GlobalTransform] and [TiledMapSettings]
+    /// of the map entity.
+    /// Layers own offset is not taken into account.
+    /// Returns `None` if the position is outside of the map or if the map is infinite.
+    ///
+    /// Isometric (diamond) maps are handled by [TiledMap::iso_pick].
+    ///
+    /// Note that returned [TilePos] uses `bevy_ecs_tilemap` convention, where `y` axis points up.
+    pub fn tile_at_world_position(
+        &self,
+        world_position: Vec2,
+        map_transform: &GlobalTransform,
+        settings: &TiledMapSettings,
+    ) -> Option<TilePos> {
+        if self.map.infinite() {
+            return None;
+        }
+        if matches!(
+            get_map_type(&self.map),
+            TilemapType::Isometric(IsoCoordSystem::Diamond)
+        ) {
+            return self.iso_pick(world_position, map_transform, settings);
+        }
+        let map_position = map_transform
+            .affine()
+            .inverse()
+            .transform_point3(world_position.extend(0.));
+        let map_position = self
+            .positioning_transform(settings)
+            .compute_affine()
+            .inverse()
+            .transform_point3(map_position);
+        tile_pos_from_map_position(
+            map_position.truncate(),
+            &get_map_type(&self.map),
+            &get_map_size(&self.map),
+            &get_grid_size(&self.map),
+        )
+    }
+
+    /// Get the [TilePos] of an isometric (diamond) map located at given world position.
+    ///
+    /// `map_transform` and `settings` are the [GlobalTransform] and [TiledMapSettings]
+    /// of the map entity.
+    /// Layers own offset is not taken into account.
+    /// Returns `None` if the map is not an isometric diamond map or if the position is outside the map diamond.
+    ///
+    /// See [iso_tile_pos_from_map_position] for more details.
+    pub fn iso_pick(
+        &self,
+        world_position: Vec2,
+        map_transform: &GlobalTransform,
+        settings: &TiledMapSettings,
+    ) -> Option<TilePos> {
+        if !matches!(
+            get_map_type(&self.map),
+            TilemapType::Isometric(IsoCoordSystem::Diamond)
+        ) {
+            return None;
+        }
+        let map_position = map_transform
+            .affine()
+            .inverse()
+            .transform_point3(world_position.extend(0.));
+        let map_position = self
+            .positioning_transform(settings)
+            .compute_affine()
+            .inverse()
+            .transform_point3(map_position);
+        iso_tile_pos_from_map_position(
+            map_position.truncate(),
+            &get_map_size(&self.map),
+            &get_grid_size(&self.map),
+        )
+    }
+
+    /// Create a [TiledMap] from in-memory `.tmx` bytes.
+    ///
+    /// `map_path` is used to resolve relative paths of tilesets and images
+    /// referenced by the map, which are loaded using provided [TiledMapDependencies].
+    /// It can be used from a custom [AssetLoader] to load maps which are not
+    /// stored as `.tmx` files, or outside of any [AssetLoader] for instance for maps
+    /// downloaded at runtime, which can then be added to [`Assets<TiledMap>`].
+    ///
+    /// ```rust,no_run
+    /// use bevy::asset::AssetSourceId;
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// fn add_downloaded_map(
+    ///     mut commands: Commands,
+    ///     asset_server: Res<AssetServer>,
+    ///     mut maps: ResMut<Assets<TiledMap>>,
+    /// ) {
+    ///     let (map_bytes, tileset_bytes) = (vec![], vec![]); // Downloaded content
+    ///     let options = TiledMapBytesOptions::new().with_file("tileset.tsx", tileset_bytes);
+    ///     let map = TiledMap::from_bytes(
+    ///         &map_bytes,
+    ///         Path::new("map.tmx"),
+    ///         TiledMapDependencies::AssetServer(&asset_server, AssetSourceId::Default),
+    ///         &options,
+    ///     )
+    ///     .unwrap();
+    ///     commands.spawn(TiledMapHandle(maps.add(map)));
+    /// }
+    /// ```
+    pub fn from_bytes(
+        bytes: &[u8],
+        map_path: &Path,
+        mut dependencies: TiledMapDependencies<'_, '_>,
+        options: &TiledMapBytesOptions<'_>,
+    ) -> Result<Self, TiledAssetLoaderError> {
+        log::info!("Start loading map '{}'", map_path.display());
 
-        let map_path = load_context.path().to_path_buf();
         let map = {
             // Allow the loader to also load tileset images.
             let mut loader = tiled::Loader::with_cache_and_reader(
                 tiled::DefaultResourceCache::new(),
-                BytesResourceReader::new(&bytes, load_context),
+                BytesResourceReader::new(bytes, &options.files, dependencies.load_context()),
             );
             // Load the map and all tiles.
-            loader.load_tmx_map(&map_path).map_err(|e| {
+            loader.load_tmx_map(map_path).map_err(|e| {
                 std::io::Error::new(ErrorKind::Other, format!("Could not load TMX map: {e}"))
             })?
         };
@@ -136,9 +648,10 @@ impl AssetLoader for TiledLoader {
                         let mut tile_images: Vec<Handle<Image>> = Vec::new();
                         for (tile_id, tile) in tileset.tiles() {
                             if let Some(img) = &tile.image {
-                                let asset_path = AssetPath::from(img.source.clone());
+                                let asset_path =
+                                    tiled_asset_path(&img.source, &dependencies.source());
                                 log::debug!("Loading tile image from {asset_path:?} as image ({tileset_index}, {tile_id})");
-                                let texture: Handle<Image> = load_context.load(asset_path.clone());
+                                let texture = dependencies.load_image(asset_path);
                                 tile_image_offsets
                                     .insert((tileset_index, tile_id), tile_images.len() as u32);
                                 tile_images.push(texture.clone());
@@ -149,10 +662,10 @@ impl AssetLoader for TiledLoader {
                     }
                 }
                 Some(img) => {
-                    let asset_path = AssetPath::from(img.source.clone());
-                    let texture: Handle<Image> = load_context.load(asset_path.clone());
+                    let asset_path = tiled_asset_path(&img.source, &dependencies.source());
+                    let texture = dependencies.load_image(asset_path);
 
-                    TilemapTexture::Single(texture.clone())
+                    TilemapTexture::Single(texture)
                 }
             };
 
@@ -160,27 +673,518 @@ impl AssetLoader for TiledLoader {
         }
 
         #[cfg(feature = "user_properties")]
-        let properties =
-            DeserializedMapProperties::load(&map, self.registry.read().deref(), load_context);
+        let properties = match options.registry {
+            Some(registry) => DeserializedMapProperties::load(
+                &map,
+                registry,
+                dependencies.load_context(),
+                options.color_format,
+            ),
+            None => DeserializedMapProperties::default(),
+        };
 
         #[cfg(feature = "user_properties")]
         trace!(?properties, "user properties");
 
+        let layer_positioning = options
+            .layer_positioning_property
+            .as_ref()
+            .and_then(|property| layer_positioning_from_property(&map, property));
+
         let asset_map = TiledMap {
             map,
+            parallax_origin: read_parallax_origin(bytes),
             tilemap_textures,
+            layer_positioning,
             #[cfg(feature = "user_properties")]
             properties,
             #[cfg(not(feature = "atlas"))]
             tile_image_offsets,
         };
 
-        log::info!("Loaded map '{}'", load_context.path().display());
+        log::info!("Loaded map '{}'", map_path.display());
         Ok(asset_map)
     }
+}
+
+/// Read [LayerPositioning] from a map string property.
+fn layer_positioning_from_property(map: &tiled::Map, property: &str) -> Option<LayerPositioning> {
+    match map.properties.get(property)? {
+        tiled::PropertyValue::StringValue(value) => match value.as_str() {
+            "TiledOffset" => Some(LayerPositioning::TiledOffset),
+            "Centered" => Some(LayerPositioning::Centered),
+            _ => {
+                log::warn!("Invalid layer positioning '{value}' for property '{property}': expected 'TiledOffset' or 'Centered'");
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Read the map parallax origin from raw `.tmx` bytes, since it is not exposed by the `tiled` crate.
+fn read_parallax_origin(bytes: &[u8]) -> Vec2 {
+    for event in xml::reader::EventReader::new(bytes) {
+        match event {
+            // Only look at the root element, which should be the map
+            Ok(xml::reader::XmlEvent::StartElement {
+                name, attributes, ..
+            }) if name.local_name == "map" => {
+                let attribute = |name: &str| {
+                    attributes
+                        .iter()
+                        .find(|attribute| attribute.name.local_name == name)
+                        .and_then(|attribute| attribute.value.parse::<f32>().ok())
+                        .unwrap_or_default()
+                };
+                return Vec2::new(attribute("parallaxoriginx"), attribute("parallaxoriginy"));
+            }
+            Ok(xml::reader::XmlEvent::StartElement { .. }) | Err(_) => break,
+            _ => {}
+        }
+    }
+    Vec2::ZERO
+}
+
+/// How assets referenced by a Tiled map are loaded, see [TiledMap::from_bytes].
+pub enum TiledMapDependencies<'a, 'ctx> {
+    /// Load external tilesets and images through the [LoadContext] of an [AssetLoader],
+    /// so they are tracked as dependencies of the map asset.
+    LoadContext(&'a mut LoadContext<'ctx>),
+    /// Load images through the [AssetServer], from provided [AssetSourceId].
+    ///
+    /// External tilesets cannot be read through the [AssetServer]: their content must
+    /// be provided using [TiledMapBytesOptions::with_file]. `file` custom properties
+    /// are not loaded.
+    AssetServer(&'a AssetServer, AssetSourceId<'static>),
+}
+
+impl<'ctx> TiledMapDependencies<'_, 'ctx> {
+    fn load_context(&mut self) -> Option<&mut LoadContext<'ctx>> {
+        match self {
+            Self::LoadContext(load_context) => Some(&mut **load_context),
+            Self::AssetServer(..) => None,
+        }
+    }
+
+    fn source(&self) -> AssetSourceId<'static> {
+        match self {
+            Self::LoadContext(load_context) => load_context.asset_path().source().clone_owned(),
+            Self::AssetServer(_, source) => source.clone(),
+        }
+    }
+
+    fn load_image(&mut self, asset_path: AssetPath<'static>) -> Handle<Image> {
+        match self {
+            Self::LoadContext(load_context) => load_context.load(asset_path),
+            Self::AssetServer(asset_server, _) => asset_server.load(asset_path),
+        }
+    }
+}
+
+/// Options for [TiledMap::from_bytes].
+#[derive(Default, Clone)]
+pub struct TiledMapBytesOptions<'a> {
+    #[cfg_attr(not(feature = "user_properties"), allow(dead_code))]
+    registry: Option<&'a TypeRegistry>,
+    #[cfg(feature = "user_properties")]
+    color_format: TiledColorFormat,
+    layer_positioning_property: Option<String>,
+    files: HashMap<PathBuf, Arc<[u8]>>,
+}
+
+impl<'a> TiledMapBytesOptions<'a> {
+    /// Create default options: custom properties are not loaded and
+    /// external files are read from the [LoadContext], if any.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use provided [TypeRegistry] to load custom properties.
+    ///
+    /// Only has an effect when the `user_properties` feature is enabled.
+    pub fn with_registry(mut self, registry: &'a TypeRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Set how colors of custom properties are interpreted.
+    #[cfg(feature = "user_properties")]
+    pub fn with_color_format(mut self, color_format: TiledColorFormat) -> Self {
+        self.color_format = color_format;
+        self
+    }
+
+    /// Read the map [LayerPositioning] from provided custom property,
+    /// see [TiledMapPluginConfig::layer_positioning_property](crate::TiledMapPluginConfig::layer_positioning_property).
+    pub fn with_layer_positioning_property(mut self, property: impl Into<String>) -> Self {
+        self.layer_positioning_property = Some(property.into());
+        self
+    }
+
+    /// Provide the content of a file referenced by the map, for instance an external tileset.
+    ///
+    /// `path` is the file path as resolved by Tiled, relative to the map directory.
+    pub fn with_file(mut self, path: impl Into<PathBuf>, bytes: impl Into<Arc<[u8]>>) -> Self {
+        self.files.insert(path.into(), bytes.into());
+        self
+    }
+}
+
+struct BytesResourceReader<'a, 'b> {
+    bytes: Arc<[u8]>,
+    files: &'a HashMap<PathBuf, Arc<[u8]>>,
+    context: Option<&'a mut LoadContext<'b>>,
+}
+impl<'a, 'b> BytesResourceReader<'a, 'b> {
+    fn new(
+        bytes: &'a [u8],
+        files: &'a HashMap<PathBuf, Arc<[u8]>>,
+        context: Option<&'a mut LoadContext<'b>>,
+    ) -> Self {
+        Self {
+            bytes: Arc::from(bytes),
+            files,
+            context,
+        }
+    }
+}
+
+impl<'a> tiled::ResourceReader for BytesResourceReader<'a, '_> {
+    type Resource = Box<dyn Read + 'a>;
+    type Error = IoError;
+
+    fn read_from(&mut self, path: &Path) -> std::result::Result<Self::Resource, Self::Error> {
+        if let Some(data) = self.files.get(path) {
+            return Ok(Box::new(Cursor::new(data.clone())));
+        }
+        if let Some(extension) = path.extension() {
+            if extension == "tsx" {
+                let Some(context) = self.context.as_deref_mut() else {
+                    return Err(IoError::new(
+                        ErrorKind::NotFound,
+                        format!("external tileset '{}' was not provided", path.display()),
+                    ));
+                };
+                let asset_path = tiled_asset_path(path, context.asset_path().source());
+                let future = context.read_asset_bytes(asset_path);
+                let data = futures_lite::future::block_on(future)
+                    .map_err(|err| IoError::new(ErrorKind::NotFound, err))?;
+                return Ok(Box::new(Cursor::new(data)));
+            }
+        }
+        Ok(Box::new(Cursor::new(self.bytes.clone())))
+    }
+}
+
+/// Build an [AssetPath] from a path resolved by `tiled`, using provided [AssetSourceId].
+///
+/// `tiled` resolves paths by joining them with the map directory, which can result
+/// in paths like `maps/../tiles/tileset.png`: these are normalized so they are
+/// also valid for asset sources which are not backed by a filesystem, like HTTP sources.
+pub(crate) fn tiled_asset_path(path: &Path, source: &AssetSourceId) -> AssetPath<'static> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    AssetPath::from(normalized).with_source(source.clone_owned())
+}
+
+pub(crate) struct TiledLoader {
+    pub layer_positioning_property: Option<String>,
+    #[cfg(feature = "user_properties")]
+    pub registry: TypeRegistryArc,
+    #[cfg(feature = "user_properties")]
+    pub color_format: TiledColorFormat,
+    #[cfg(feature = "user_properties")]
+    pub project_types: Option<HashSet<String>>,
+}
+
+impl FromWorld for TiledLoader {
+    fn from_world(_world: &mut World) -> Self {
+        Self {
+            layer_positioning_property: _world
+                .get_resource::<crate::TiledMapPluginConfig>()
+                .and_then(|config| config.layer_positioning_property.clone()),
+            #[cfg(feature = "user_properties")]
+            registry: _world.resource::<AppTypeRegistry>().0.clone(),
+            #[cfg(feature = "user_properties")]
+            color_format: _world
+                .get_resource::<crate::TiledMapPluginConfig>()
+                .map(|config| config.color_format)
+                .unwrap_or_default(),
+            #[cfg(feature = "user_properties")]
+            project_types: _world
+                .get_resource::<crate::TiledMapPluginConfig>()
+                .and_then(|config| config.tiled_project_file.as_ref())
+                .and_then(|path| {
+                    read_project_types(path)
+                        .inspect_err(|e| {
+                            log::error!("Could not read Tiled project '{}': {e}", path.display())
+                        })
+                        .ok()
+                }),
+        }
+    }
+}
+
+/// [TiledMap] loading error.
+#[derive(Debug, thiserror::Error)]
+pub enum TiledAssetLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not load Tiled file: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl AssetLoader for TiledLoader {
+    type Asset = TiledMap;
+    type Settings = ();
+    type Error = TiledAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let map_path = load_context.path().to_path_buf();
+        #[cfg(feature = "user_properties")]
+        let registry = self.registry.read();
+        let mut options = TiledMapBytesOptions::new();
+        if let Some(property) = &self.layer_positioning_property {
+            options = options.with_layer_positioning_property(property);
+        }
+        #[cfg(feature = "user_properties")]
+        let options = options
+            .with_registry(registry.deref())
+            .with_color_format(self.color_format);
+        let tiled_map = TiledMap::from_bytes(
+            &bytes,
+            &map_path,
+            TiledMapDependencies::LoadContext(load_context),
+            &options,
+        )?;
+
+        #[cfg(feature = "user_properties")]
+        if let Some(project_types) = &self.project_types {
+            validate_map_types(&tiled_map.map, &map_path, project_types, registry.deref());
+        }
+
+        Ok(tiled_map)
+    }
 
     fn extensions(&self) -> &[&str] {
         static EXTENSIONS: &[&str] = &["tmx"];
         EXTENSIONS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiled_asset_path_normalization() {
+        let path = tiled_asset_path(
+            Path::new("maps/../tiles/./tileset.png"),
+            &AssetSourceId::from("remote"),
+        );
+        assert_eq!(path.path(), Path::new("tiles/tileset.png"));
+        assert_eq!(path.source(), &AssetSourceId::from("remote"));
+        assert_eq!(path.to_string(), "remote://tiles/tileset.png");
+    }
+
+    #[test]
+    fn parallax_origin() {
+        assert_eq!(
+            read_parallax_origin(
+                br#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" width="4" height="4" parallaxoriginx="-16" parallaxoriginy="32.5">
+</map>"#
+            ),
+            Vec2::new(-16., 32.5)
+        );
+        assert_eq!(
+            read_parallax_origin(br#"<map version="1.10" width="4" height="4"></map>"#),
+            Vec2::ZERO
+        );
+        // Any valid XML formatting is supported
+        assert_eq!(
+            read_parallax_origin(
+                b"<?xml version='1.0' encoding='UTF-8'?>\n<!-- <map parallaxoriginx=\"1\"> -->\n<map\n\tversion='1.10'\n\tparallaxoriginx='8'\tparallaxoriginy = \"-4.5\">\n</map>"
+            ),
+            Vec2::new(8., -4.5)
+        );
+        assert_eq!(read_parallax_origin(b"not a map"), Vec2::ZERO);
+    }
+
+    #[test]
+    fn from_bytes_without_load_context() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>();
+        let asset_server = app.world().resource::<AssetServer>();
+
+        let map_bytes = std::fs::read("assets/finite.tmx").unwrap();
+        let options = TiledMapBytesOptions::new().with_file(
+            "Tileset1.tsx",
+            std::fs::read("assets/Tileset1.tsx").unwrap(),
+        );
+        let tiled_map = TiledMap::from_bytes(
+            &map_bytes,
+            Path::new("finite.tmx"),
+            TiledMapDependencies::AssetServer(asset_server, AssetSourceId::Default),
+            &options,
+        )
+        .unwrap();
+        assert_eq!((tiled_map.map.width, tiled_map.map.height), (10, 10));
+        assert_eq!(
+            tiled_map.tilemap_textures.len(),
+            tiled_map.map.tilesets().len()
+        );
+
+        // External tilesets must be provided when there is no LoadContext
+        assert!(TiledMap::from_bytes(
+            &map_bytes,
+            Path::new("finite.tmx"),
+            TiledMapDependencies::AssetServer(asset_server, AssetSourceId::Default),
+            &TiledMapBytesOptions::new(),
+        )
+        .is_err());
+    }
+
+    /// Build a [TiledMap] without textures from `.tmx` bytes.
+    fn test_map(bytes: &[u8], options: &TiledMapBytesOptions) -> TiledMap {
+        let map = tiled::Loader::with_cache_and_reader(
+            tiled::DefaultResourceCache::new(),
+            BytesResourceReader::new(bytes, &options.files, None),
+        )
+        .load_tmx_map("test.tmx")
+        .unwrap();
+        TiledMap {
+            layer_positioning: options
+                .layer_positioning_property
+                .as_ref()
+                .and_then(|property| layer_positioning_from_property(&map, property)),
+            map,
+            parallax_origin: Vec2::ZERO,
+            tilemap_textures: HashMap::default(),
+            #[cfg(feature = "user_properties")]
+            properties: DeserializedMapProperties::default(),
+            #[cfg(not(feature = "atlas"))]
+            tile_image_offsets: HashMap::default(),
+        }
+    }
+
+    #[test]
+    fn layer_positioning_property() {
+        let bytes = br#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" renderorder="right-down" width="4" height="4" tilewidth="16" tileheight="16" infinite="0" nextlayerid="1" nextobjectid="1">
+ <properties>
+  <property name="positioning" value="Centered"/>
+ </properties>
+</map>"#;
+        let default_settings = TiledMapSettings::default();
+        let explicit_settings = TiledMapSettings {
+            layer_positioning: LayerPositioning::Centered,
+            ..default()
+        };
+
+        // Property is not read if not configured
+        let tiled_map = test_map(bytes, &TiledMapBytesOptions::new());
+        assert_eq!(tiled_map.layer_positioning, None);
+        assert_eq!(
+            tiled_map.layer_positioning(&default_settings),
+            LayerPositioning::TiledOffset
+        );
+        assert_eq!(
+            tiled_map.layer_positioning(&explicit_settings),
+            LayerPositioning::Centered
+        );
+
+        // Property is used when settings are left to their default value
+        let tiled_map = test_map(
+            bytes,
+            &TiledMapBytesOptions::new().with_layer_positioning_property("positioning"),
+        );
+        assert_eq!(
+            tiled_map.layer_positioning,
+            Some(LayerPositioning::Centered)
+        );
+        assert_eq!(
+            tiled_map.layer_positioning(&default_settings),
+            LayerPositioning::Centered
+        );
+        assert_eq!(
+            tiled_map.positioning_transform(&default_settings),
+            tiled_map.positioning_transform(&explicit_settings)
+        );
+    }
+
+    #[test]
+    fn diamond_map_picking() {
+        let tiled_map = test_map(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="isometric" renderorder="right-down" width="4" height="4" tilewidth="32" tileheight="16" infinite="0" nextlayerid="1" nextobjectid="1">
+</map>"#,
+            &TiledMapBytesOptions::new(),
+        );
+
+        let settings = TiledMapSettings::default();
+        let map_transform = GlobalTransform::from_translation(Vec3::new(100., -50., 0.));
+        let to_world = |map_position: Vec2| {
+            map_transform
+                .transform_point(
+                    tiled_map
+                        .positioning_transform(&settings)
+                        .transform_point(map_position.extend(0.)),
+                )
+                .truncate()
+        };
+        let grid_size = get_grid_size(&tiled_map.map);
+        let map_type = get_map_type(&tiled_map.map);
+        for x in 0..4 {
+            for y in 0..4 {
+                let tile_pos = TilePos::new(x, y);
+                // Tile center, as spawned by the loader
+                let center = tile_pos.center_in_world(&grid_size, &map_type)
+                    + Vec2::new(grid_size.x / 2., grid_size.y / 2.);
+                // Stay inside the tile diamond when moving towards its corners
+                for offset in [
+                    Vec2::ZERO,
+                    Vec2::new(grid_size.x * 0.45, 0.),
+                    Vec2::new(0., -grid_size.y * 0.45),
+                ] {
+                    let world_position = to_world(center + offset);
+                    assert_eq!(
+                        tiled_map.iso_pick(world_position, &map_transform, &settings),
+                        Some(tile_pos)
+                    );
+                    assert_eq!(
+                        tiled_map.tile_at_world_position(world_position, &map_transform, &settings),
+                        Some(tile_pos)
+                    );
+                }
+            }
+        }
+
+        // Left corner of the map diamond is tile (0, 0) left corner
+        let center = TilePos::new(0, 0).center_in_world(&grid_size, &map_type)
+            + Vec2::new(grid_size.x / 2., grid_size.y / 2.);
+        let outside = to_world(center - Vec2::new(grid_size.x * 0.55, 0.));
+        assert_eq!(tiled_map.iso_pick(outside, &map_transform, &settings), None);
+        assert_eq!(
+            tiled_map.tile_at_world_position(outside, &map_transform, &settings),
+            None
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::prelude::*;
 use bevy::{prelude::*, utils::HashMap};
+use bevy_ecs_tilemap::prelude::TilePos;
 use tiled::TileId;
 
 /// [Component] holding Tiled related settings.
@@ -11,6 +12,9 @@ use tiled::TileId;
 #[derive(Component, Copy, Clone)]
 pub struct TiledMapSettings {
     /// Specify which layer positioning strategy should be applied to the map.
+    ///
+    /// When left to its default value, it can be overridden by a map custom property,
+    /// see [TiledMapPluginConfig::layer_positioning_property](crate::TiledMapPluginConfig::layer_positioning_property).
     pub layer_positioning: LayerPositioning,
     /// Z-offset between two consecutives layers.
     pub layer_z_offset: f32,
@@ -28,7 +32,7 @@ impl Default for TiledMapSettings {
 /// Controls layers positioning strategy.
 ///
 /// Based upon this setting, you can determine where your layers (ie. your map) will be rendered.
-#[derive(Default, Copy, Clone)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Debug)]
 pub enum LayerPositioning {
     #[default]
     /// Do not tweak layers position and keep original Tiled coordinate system so that Bevy (0, 0) is at the bottom-left of the map.
@@ -37,6 +41,40 @@ pub enum LayerPositioning {
     Centered,
 }
 
+/// [Component] enabling y-sort for Tiled objects.
+///
+/// Can be added either to the [Entity] holding the map, to apply to all object layers,
+/// or to a given object layer [Entity].
+/// Objects Z-position (relative to their layer) will then be updated each frame from their world Y-position:
+/// objects lower on screen will be displayed in front of objects higher on screen.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TiledMapHandle(asset_server.load("isometric_diamond_map.tmx")),
+///         TiledYSort::default(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug)]
+pub struct TiledYSort {
+    /// Factor applied to the object world Y-position to compute its Z-position.
+    ///
+    /// Resulting Z-position should stay within [TiledMapSettings::layer_z_offset]
+    /// so objects do not overlap with other layers.
+    pub scale: f32,
+}
+
+impl Default for TiledYSort {
+    fn default() -> Self {
+        Self { scale: 0.01 }
+    }
+}
+
 /// Marker [Component] to trigger a map respawn.
 ///
 /// Must be added to the [Entity] holding the map.
@@ -57,6 +95,38 @@ pub enum LayerPositioning {
 #[derive(Component)]
 pub struct RespawnTiledMap;
 
+/// Marker [Component] to mirror a Tiled map horizontally.
+///
+/// Must be added to the [Entity] holding the map before it is spawned,
+/// or followed by a [RespawnTiledMap] to apply it to an already spawned map.
+/// Tiles, objects and images are mirrored along the map vertical center axis:
+/// positions are mirrored in Tiled coordinates, before being converted to Bevy coordinates,
+/// tiles flip flags are toggled and objects [Transform] get a negative X-scale.
+///
+/// Only finite orthogonal maps can be mirrored: this component is ignored,
+/// with a warning, for infinite, isometric and hexagonal maps.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         TiledMapHandle(asset_server.load("finite.tmx")),
+///         TiledMapFlipX,
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct TiledMapFlipX;
+
+/// Marker [Component] to mirror a Tiled map vertically.
+///
+/// Works the same way as [TiledMapFlipX], along the map horizontal center axis.
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct TiledMapFlipY;
+
 /// [Component] storing maps to navigate from Tiled ID to Bevy [Entity].
 ///
 /// Should not be manually inserted but can be accessed from the map [Entity].
@@ -71,6 +141,242 @@ pub struct TiledIdStorage {
     /// Note that we can have multiple entities (several instances)
     /// of the same tile.
     pub tiles: HashMap<(String, TileId), Vec<Entity>>,
+    /// Map of objects and tiles entities, using the [TypeId](std::any::TypeId)
+    /// of the custom properties they were given as key.
+    #[cfg(feature = "user_properties")]
+    pub classes: HashMap<std::any::TypeId, Vec<Entity>>,
+}
+
+impl TiledIdStorage {
+    /// Retrieve layers entities whose name matches provided [ObjectNames].
+    ///
+    /// Provided [TiledMap] should be the one this storage belongs to.
+    pub fn get_layers_by_name<'a>(
+        &'a self,
+        tiled_map: &'a TiledMap,
+        names: &ObjectNames,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        let filter = ObjectNameFilter::from(names);
+        tiled_map
+            .map
+            .layers()
+            .filter(move |layer| filter.contains(&layer.name))
+            .filter_map(|layer| self.layers.get(&layer.id()).copied())
+    }
+
+    /// Retrieve the entity of the first layer with provided name.
+    ///
+    /// Provided [TiledMap] should be the one this storage belongs to.
+    pub fn layer_by_name(&self, tiled_map: &TiledMap, name: &str) -> Option<Entity> {
+        tiled_map
+            .map
+            .layers()
+            .find(|layer| layer.name == name)
+            .and_then(|layer| self.layers.get(&layer.id()).copied())
+    }
+
+    /// Retrieve the entity of the first object with provided name.
+    ///
+    /// Provided [TiledMap] should be the one this storage belongs to.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// fn find_spawn(
+    ///     map_asset: Res<Assets<TiledMap>>,
+    ///     q_map: Query<(&TiledMapHandle, &TiledIdStorage)>,
+    /// ) {
+    ///     for (map_handle, storage) in q_map.iter() {
+    ///         if let Some(tiled_map) = map_asset.get(&map_handle.0) {
+    ///             if let Some(spawn) = storage.object_by_name(tiled_map, "PlayerSpawn") {
+    ///                 info!("Found player spawn {spawn:?}");
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn object_by_name(&self, tiled_map: &TiledMap, name: &str) -> Option<Entity> {
+        tiled_map
+            .map
+            .layers()
+            .filter_map(|layer| layer.as_object_layer())
+            .flat_map(|layer| layer.objects().collect::<Vec<_>>())
+            .find(|object| object.name == name)
+            .and_then(|object| self.objects.get(&object.id()).copied())
+    }
+
+    /// Retrieve objects and tiles entities which were given the `T` custom property.
+    ///
+    /// Only top-level properties are indexed: if `T` was inserted as part of a [Bundle],
+    /// you should use the [Bundle] type instead.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// #[derive(Component, Default, Reflect)]
+    /// #[reflect(Component, Default)]
+    /// struct SpawnPoint;
+    ///
+    /// fn spawn_points(map_query: Query<&TiledIdStorage, With<TiledMapMarker>>) {
+    ///     for storage in map_query.iter() {
+    ///         for entity in storage.entities_with_class::<SpawnPoint>() {
+    ///             info!("Found spawn point {entity:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "user_properties")]
+    pub fn entities_with_class<T: 'static>(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.classes
+            .get(&std::any::TypeId::of::<T>())
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Set [Visibility] of layers whose name matches provided [ObjectNames].
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// fn hide_fog(
+    ///     mut commands: Commands,
+    ///     map_asset: Res<Assets<TiledMap>>,
+    ///     q_map: Query<(&TiledMapHandle, &TiledIdStorage)>,
+    /// ) {
+    ///     for (map_handle, storage) in q_map.iter() {
+    ///         if let Some(tiled_map) = map_asset.get(&map_handle.0) {
+    ///             storage.set_layers_visibility(
+    ///                 &mut commands,
+    ///                 tiled_map,
+    ///                 &ObjectNames::Names(vec![String::from("fog")]),
+    ///                 Visibility::Hidden,
+    ///             );
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn set_layers_visibility(
+        &self,
+        commands: &mut Commands,
+        tiled_map: &TiledMap,
+        names: &ObjectNames,
+        visibility: Visibility,
+    ) {
+        for layer_entity in self.get_layers_by_name(tiled_map, names) {
+            commands.entity(layer_entity).insert(visibility);
+        }
+    }
+
+    /// Create a [TiledIdStorageSnapshot] of this storage.
+    ///
+    /// Entities are replaced by their position: [Transform] translation for objects
+    /// and [TilePos] for tiles. Entries are sorted so snapshots can be compared.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    /// use bevy_ecs_tilemap::prelude::*;
+    ///
+    /// fn snapshot(
+    ///     q_map: Query<&TiledIdStorage>,
+    ///     q_transforms: Query<&Transform>,
+    ///     q_tiles: Query<&TilePos>,
+    /// ) {
+    ///     for storage in q_map.iter() {
+    ///         info!("{:?}", storage.snapshot(&q_transforms, &q_tiles));
+    ///     }
+    /// }
+    /// ```
+    pub fn snapshot(
+        &self,
+        q_transforms: &Query<&Transform>,
+        q_tiles: &Query<&TilePos>,
+    ) -> TiledIdStorageSnapshot {
+        let mut layers = self.layers.keys().copied().collect::<Vec<_>>();
+        layers.sort();
+
+        let mut objects = self
+            .objects
+            .iter()
+            .filter_map(|(id, entity)| {
+                q_transforms
+                    .get(*entity)
+                    .ok()
+                    .map(|transform| TiledObjectSnapshot {
+                        id: *id,
+                        position: transform.translation.truncate().to_array(),
+                    })
+            })
+            .collect::<Vec<_>>();
+        objects.sort_by_key(|object| object.id);
+
+        let mut tiles = self
+            .tiles
+            .iter()
+            .flat_map(|((tileset, tile_id), entities)| {
+                entities.iter().filter_map(|entity| {
+                    q_tiles.get(*entity).ok().map(|tile_pos| TiledTileSnapshot {
+                        tileset: tileset.clone(),
+                        tile_id: *tile_id,
+                        position: [tile_pos.x, tile_pos.y],
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        tiles.sort_by(|a, b| {
+            (&a.tileset, a.tile_id, a.position).cmp(&(&b.tileset, b.tile_id, b.position))
+        });
+
+        TiledIdStorageSnapshot {
+            layers,
+            objects,
+            tiles,
+        }
+    }
+}
+
+/// Plain data snapshot of a [TiledIdStorage], using Tiled IDs and positions instead of [Entity].
+///
+/// Can be serialized when the `serde` feature is enabled.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TiledIdStorageSnapshot {
+    /// Tiled IDs of spawned layers
+    pub layers: Vec<u32>,
+    /// Spawned objects
+    pub objects: Vec<TiledObjectSnapshot>,
+    /// Spawned tiles
+    pub tiles: Vec<TiledTileSnapshot>,
+}
+
+/// Object entry of a [TiledIdStorageSnapshot].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TiledObjectSnapshot {
+    /// Tiled ID of the object
+    pub id: u32,
+    /// Object position, relative to its layer
+    pub position: [f32; 2],
+}
+
+/// Tile entry of a [TiledIdStorageSnapshot].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TiledTileSnapshot {
+    /// Name of the tileset the tile belongs to
+    pub tileset: String,
+    /// Tile ID in its tileset
+    pub tile_id: TileId,
+    /// Tile position, using `bevy_ecs_tilemap` convention
+    pub position: [u32; 2],
 }
 
 /// Marker [Component] for a Tiled map.
@@ -78,6 +384,37 @@ pub struct TiledIdStorage {
 #[require(Visibility, Transform)]
 pub struct TiledMapMarker;
 
+/// [Component] referencing the map [Entity] a Tiled entity belongs to.
+///
+/// Automatically added to layers, tilemaps, tiles, objects and images entities.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TiledMapReference(pub Entity);
+
+impl TiledMapReference {
+    /// Retrieve the map [Entity] owning provided [Entity].
+    ///
+    /// Returns `None` if provided [Entity] does not belong to a Tiled map.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// fn object_map(
+    ///     q_objects: Query<Entity, With<TiledMapObject>>,
+    ///     q_map_ref: Query<&TiledMapReference>,
+    /// ) {
+    ///     for object in q_objects.iter() {
+    ///         let map = TiledMapReference::map_of(object, &q_map_ref);
+    ///         info!("Object {object:?} belongs to map {map:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn map_of(entity: Entity, q_map_ref: &Query<&TiledMapReference>) -> Option<Entity> {
+        q_map_ref.get(entity).ok().map(|map_ref| map_ref.0)
+    }
+}
+
 /// Marker [Component] for a Tiled map layer.
 #[derive(Component)]
 #[require(Visibility, Transform)]
@@ -88,6 +425,70 @@ pub struct TiledMapLayer {
     pub map_handle_id: AssetId<TiledMap>,
 }
 
+/// [Component] controlling a Tiled layer opacity at runtime.
+///
+/// Can be added to a [TiledMapLayer] [Entity] to update the alpha of its tiles and images.
+/// This opacity is multiplied with the layer opacity set in Tiled.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn fade_layers(
+///     mut commands: Commands,
+///     time: Res<Time>,
+///     q_layers: Query<Entity, With<TiledMapTileLayer>>,
+/// ) {
+///     let opacity = time.elapsed_secs().sin().abs();
+///     for layer in q_layers.iter() {
+///         commands.entity(layer).insert(TiledLayerOpacity(opacity));
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct TiledLayerOpacity(pub f32);
+
+impl Default for TiledLayerOpacity {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Layer tint color and opacity as set in Tiled.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct TiledLayerAuthoredColor {
+    pub(crate) tint: Color,
+    pub(crate) opacity: f32,
+}
+
+/// [Component] tinting a whole Tiled map at runtime.
+///
+/// Can be added to the map [Entity] to update the color of all its tiles and images.
+/// This color is multiplied with layers tint color and opacity set in Tiled.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn night(mut commands: Commands, q_maps: Query<Entity, With<TiledMapMarker>>) {
+///     for map in q_maps.iter() {
+///         commands
+///             .entity(map)
+///             .insert(TiledMapTint(Color::srgb(0.3, 0.3, 0.6)));
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct TiledMapTint(pub Color);
+
+impl Default for TiledMapTint {
+    fn default() -> Self {
+        Self(Color::WHITE)
+    }
+}
+
 /// Marker [Component] for a Tiled map tile layer.
 #[derive(Component)]
 #[require(Visibility, Transform)]
@@ -122,11 +523,81 @@ pub struct TiledMapImageLayer;
 #[derive(Component)]
 pub struct TiledMapTile;
 
+/// [Component] storing from which tileset and tile a Tiled map tile comes from.
+///
+/// Automatically added to tiles entities.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq, Eq)]
+#[reflect(Component, Debug)]
+pub struct TiledTileSource {
+    /// Index of the tileset in the Tiled map.
+    pub tileset_index: u32,
+    /// ID of the tile in its tileset.
+    pub tile_id: TileId,
+}
+
+/// [Component] storing the probability of a Tiled map tile, as used by Tiled random mode.
+///
+/// Only added to tiles entities whose probability is not the default one (1.0).
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Debug)]
+pub struct TiledTileProbability(pub f32);
+
+/// [Component] storing the Tiled ID of a layer.
+///
+/// Automatically added to layers entities.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug)]
+pub struct TiledLayerId(pub u32);
+
+/// [Component] storing the Tiled ID of an object.
+///
+/// Automatically added to objects entities.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug)]
+pub struct TiledObjectId(pub u32);
+
+/// [Component] storing a layer parallax factors, as set in Tiled.
+///
+/// Automatically added to layers entities which have a parallax factor different from 1.0.
+/// The layer [Transform] is then updated each frame according to the first active [Camera] position,
+/// relative to the map parallax origin, so that the layer scrolls at the authored rate.
+#[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
+#[reflect(Component, Debug)]
+pub struct TiledLayerParallax {
+    /// Horizontal parallax factor
+    pub parallax_x: f32,
+    /// Vertical parallax factor
+    pub parallax_y: f32,
+    /// Layer position relative to the map, when the camera is at the parallax origin
+    pub base_position: Vec2,
+    /// Map parallax origin, relative to the map
+    pub origin: Vec2,
+}
+
 /// Marker [Component] for a Tiled map object.
 #[derive(Component)]
 #[require(Visibility, Transform)]
 pub struct TiledMapObject;
 
+/// Marker [Component] for a Tiled point object.
+///
+/// Automatically added to objects entities whose shape is a point,
+/// which are typically used as spawn points or waypoints.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn spawn_points(q_points: Query<(&Name, &GlobalTransform), With<TiledObjectPoint>>) {
+///     for (name, transform) in q_points.iter() {
+///         info!("Point {name} at {:?}", transform.translation());
+///     }
+/// }
+/// ```
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub struct TiledObjectPoint;
+
 /// Marker [Component] for a Tiled image
 #[derive(Component)]
 #[require(Visibility, Transform)]
@@ -3,7 +3,10 @@
 //! You need to enable the `debug` feature to use it.
 //!
 use crate::prelude::*;
-use bevy::{color::palettes::css::RED, prelude::*};
+use bevy::{
+    color::palettes::css::{AQUA, FUCHSIA, ORANGE, RED, YELLOW},
+    prelude::*,
+};
 
 /// Debug [Gizmos] configuration
 ///
@@ -14,6 +17,14 @@ pub struct TiledMapGizmosConfig {
     pub color: Color,
     /// Length of the `arrow_2d` [Gizmos]
     pub arrow_length: Vec2,
+    /// Color of rectangle objects outline, if they should be displayed
+    pub rect_color: Option<Color>,
+    /// Color of ellipse objects outline, if they should be displayed
+    pub ellipse_color: Option<Color>,
+    /// Color of polygon objects outline, if they should be displayed
+    pub polygon_color: Option<Color>,
+    /// Color of polyline objects, if they should be displayed
+    pub polyline_color: Option<Color>,
 }
 
 impl Default for TiledMapGizmosConfig {
@@ -21,6 +32,10 @@ impl Default for TiledMapGizmosConfig {
         Self {
             color: bevy::prelude::Color::Srgba(RED),
             arrow_length: Vec2::new(0., 20.),
+            rect_color: Some(bevy::prelude::Color::Srgba(YELLOW)),
+            ellipse_color: Some(bevy::prelude::Color::Srgba(ORANGE)),
+            polygon_color: Some(bevy::prelude::Color::Srgba(AQUA)),
+            polyline_color: Some(bevy::prelude::Color::Srgba(FUCHSIA)),
         }
     }
 }
@@ -36,7 +51,8 @@ impl Default for TiledMapGizmosConfig {
 ///     .add_plugins(TiledMapDebugPlugin::default());
 /// ```
 ///
-/// This will display an `arrow_2d` [Gizmos] where your objects are.
+/// This will display an `arrow_2d` [Gizmos] where your objects are,
+/// as well as the outline of rectangle, ellipse, polygon and polyline objects.
 ///
 #[derive(Default, Clone)]
 pub struct TiledMapDebugPlugin {
@@ -47,7 +63,79 @@ pub struct TiledMapDebugPlugin {
 impl Plugin for TiledMapDebugPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.insert_resource(self.gizmos_config.clone())
-            .add_systems(Update, draw_debug_arrow);
+            .add_observer(store_object_outline)
+            .add_systems(Update, (draw_debug_arrow, draw_objects_outline));
+    }
+}
+
+/// Kind of shape of a Tiled object, used to select its outline color.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum TiledObjectShapeKind {
+    Rect,
+    Ellipse,
+    Polygon,
+    Polyline,
+}
+
+/// Object outline, in the object local referential.
+#[derive(Component)]
+struct TiledObjectOutline(TiledObjectShapeKind, Vec<Vec2>);
+
+fn store_object_outline(
+    trigger: Trigger<TiledObjectCreated>,
+    map_asset: Res<Assets<TiledMap>>,
+    mut commands: Commands,
+) {
+    let event = trigger.event();
+    let object = event.object(&map_asset);
+    let kind = match object.shape {
+        tiled::ObjectShape::Rect { .. } | tiled::ObjectShape::Text { .. } => {
+            TiledObjectShapeKind::Rect
+        }
+        tiled::ObjectShape::Ellipse { .. } => TiledObjectShapeKind::Ellipse,
+        tiled::ObjectShape::Polygon { .. } => TiledObjectShapeKind::Polygon,
+        tiled::ObjectShape::Polyline { .. } => TiledObjectShapeKind::Polyline,
+        tiled::ObjectShape::Point(_, _) => return,
+    };
+
+    let mut outline = object_shape_vertices(&object.shape);
+    // Tile objects origin is their bottom-left corner
+    if let (Some(_), tiled::ObjectShape::Rect { height, .. }) = (object.tile_data(), &object.shape)
+    {
+        outline.iter_mut().for_each(|p| p.y -= height);
+    }
+    if kind != TiledObjectShapeKind::Polyline {
+        outline.extend(outline.first().copied());
+    }
+
+    commands.entity(event.object).insert(TiledObjectOutline(
+        kind,
+        outline.into_iter().map(|p| Vec2::new(p.x, -p.y)).collect(),
+    ));
+}
+
+fn draw_objects_outline(
+    q_objects: Query<(&TiledObjectOutline, &GlobalTransform)>,
+    config: Res<TiledMapGizmosConfig>,
+    mut gizmos: Gizmos,
+) {
+    for (outline, transform) in q_objects.iter() {
+        let color = match outline.0 {
+            TiledObjectShapeKind::Rect => config.rect_color,
+            TiledObjectShapeKind::Ellipse => config.ellipse_color,
+            TiledObjectShapeKind::Polygon => config.polygon_color,
+            TiledObjectShapeKind::Polyline => config.polyline_color,
+        };
+        let Some(color) = color else {
+            continue;
+        };
+        gizmos.linestrip_2d(
+            outline
+                .1
+                .iter()
+                .map(|p| transform.transform_point(p.extend(0.)).truncate()),
+            color,
+        );
     }
 }
 
@@ -61,3 +149,229 @@ fn draw_debug_arrow(
         gizmos.arrow_2d(pos + config.arrow_length, pos, config.color);
     }
 }
+
+/// Colliders debug [Gizmos] configuration
+///
+/// Contains some settings to customize how colliders outline will appear.
+#[cfg(feature = "physics")]
+#[derive(Resource, Clone)]
+pub struct TiledColliderGizmosConfig {
+    /// Color of the colliders outline
+    pub color: Color,
+    /// Whether the colliders outline should be displayed
+    pub enabled: bool,
+    /// Key used to toggle colliders outline display, if any
+    pub toggle_key: Option<KeyCode>,
+}
+
+#[cfg(feature = "physics")]
+impl Default for TiledColliderGizmosConfig {
+    fn default() -> Self {
+        Self {
+            color: bevy::prelude::Color::Srgba(bevy::color::palettes::css::LIME),
+            enabled: true,
+            toggle_key: Some(KeyCode::F3),
+        }
+    }
+}
+
+/// `bevy_ecs_tiled` colliders debug `Plugin`
+///
+/// Draws the outline of every collider spawned from a Tiled object, as it was
+/// described in Tiled, using the collider [GlobalTransform]:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// App::new()
+///     .add_plugins(TiledColliderDebugPlugin::default());
+/// ```
+///
+/// This is independent from the physics backend: it can be useful to check
+/// whether a misplaced collider comes from the conversion or from the physics engine.
+///
+#[cfg(feature = "physics")]
+#[derive(Default, Clone)]
+pub struct TiledColliderDebugPlugin {
+    /// Colliders debug gizmos configuration
+    pub gizmos_config: TiledColliderGizmosConfig,
+}
+
+#[cfg(feature = "physics")]
+impl Plugin for TiledColliderDebugPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.insert_resource(self.gizmos_config.clone())
+            .add_observer(store_collider_outline)
+            .add_systems(Update, (toggle_colliders_outline, draw_colliders_outline));
+    }
+}
+
+/// Collider outline, in the collider local referential.
+#[cfg(feature = "physics")]
+#[derive(Component)]
+struct TiledColliderOutline(Vec<Vec2>);
+
+#[cfg(feature = "physics")]
+fn store_collider_outline(trigger: Trigger<TiledColliderCreated>, mut commands: Commands) {
+    let event = trigger.event();
+    let Some(shape) = &event.shape else {
+        return;
+    };
+
+    // Backends move the collider away from the object origin,
+    // we need to cancel this offset to get back to the Tiled shape
+    let origin = -event.collider.position;
+    let mut outline = object_shape_vertices(shape);
+    if !matches!(shape, tiled::ObjectShape::Polyline { .. }) {
+        outline.extend(outline.first().copied());
+    }
+
+    commands
+        .entity(event.collider.entity)
+        .insert(TiledColliderOutline(
+            outline
+                .into_iter()
+                .map(|p| Vec2::new(p.x, -p.y) + origin)
+                .collect(),
+        ));
+}
+
+#[cfg(feature = "physics")]
+fn toggle_colliders_outline(
+    keyboard_input: Option<Res<ButtonInput<KeyCode>>>,
+    mut config: ResMut<TiledColliderGizmosConfig>,
+) {
+    let (Some(keyboard_input), Some(key)) = (keyboard_input, config.toggle_key) else {
+        return;
+    };
+    if keyboard_input.just_pressed(key) {
+        config.enabled = !config.enabled;
+    }
+}
+
+#[cfg(feature = "physics")]
+fn draw_colliders_outline(
+    q_colliders: Query<(&TiledColliderOutline, &GlobalTransform)>,
+    config: Res<TiledColliderGizmosConfig>,
+    mut gizmos: Gizmos,
+) {
+    if !config.enabled {
+        return;
+    }
+    for (outline, transform) in q_colliders.iter() {
+        gizmos.linestrip_2d(
+            outline
+                .0
+                .iter()
+                .map(|p| transform.transform_point(p.extend(0.)).truncate()),
+            config.color,
+        );
+    }
+}
+
+/// `bevy_ecs_tiled` tile coordinates debug `Plugin`
+///
+/// Displays the [TilePos] and the Tiled ID of the tile below the mouse cursor as UI text
+/// next to the cursor, using the first active [Camera]:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// App::new()
+///     .add_plugins(TiledDebugTileCoordsPlugin);
+/// ```
+///
+/// Nothing is displayed when the cursor is outside any map.
+/// Only the topmost tile of finite maps is reported.
+/// Requires the `debug_ui` feature.
+#[cfg(feature = "debug_ui")]
+#[derive(Default, Clone)]
+pub struct TiledDebugTileCoordsPlugin;
+
+#[cfg(feature = "debug_ui")]
+impl Plugin for TiledDebugTileCoordsPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(Startup, spawn_tile_coords_text)
+            .add_systems(Update, update_tile_coords_text);
+    }
+}
+
+/// Marker for the text displaying hovered tile coordinates.
+#[cfg(feature = "debug_ui")]
+#[derive(Component)]
+struct TiledTileCoordsText;
+
+#[cfg(feature = "debug_ui")]
+fn spawn_tile_coords_text(mut commands: Commands) {
+    commands.spawn((
+        Name::new("TiledTileCoordsText"),
+        TiledTileCoordsText,
+        Text::default(),
+        TextFont::from_font_size(12.),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        Visibility::Hidden,
+    ));
+}
+
+#[cfg(feature = "debug_ui")]
+fn update_tile_coords_text(
+    q_window: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    q_maps: Query<(&TiledMapHandle, &GlobalTransform, &TiledMapSettings)>,
+    map_assets: Res<Assets<TiledMap>>,
+    mut q_text: Query<(&mut Text, &mut Node, &mut Visibility), With<TiledTileCoordsText>>,
+) {
+    let Ok((mut text, mut node, mut visibility)) = q_text.get_single_mut() else {
+        return;
+    };
+    *visibility = Visibility::Hidden;
+
+    let Some(cursor_position) = q_window.get_single().ok().and_then(|w| w.cursor_position()) else {
+        return;
+    };
+    let Some(world_position) = q_camera
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .and_then(|(camera, camera_transform)| {
+            camera
+                .viewport_to_world_2d(camera_transform, cursor_position)
+                .ok()
+        })
+    else {
+        return;
+    };
+
+    for (map_handle, map_transform, settings) in q_maps.iter() {
+        let Some(tiled_map) = map_assets.get(&map_handle.0) else {
+            continue;
+        };
+        let Some(tile_pos) =
+            tiled_map.tile_at_world_position(world_position, map_transform, settings)
+        else {
+            continue;
+        };
+
+        // Tiled y-axis points down
+        let tiled_y = tiled_map.map.height - 1 - tile_pos.y;
+        let tile_id = tiled_map
+            .map
+            .layers()
+            .filter_map(|layer| layer.as_tile_layer())
+            .filter_map(|layer| layer.get_tile(tile_pos.x as i32, tiled_y as i32))
+            .last()
+            .map(|tile| tile.id());
+
+        text.0 = match tile_id {
+            Some(id) => format!("({}, {}) id: {}", tile_pos.x, tile_pos.y, id),
+            None => format!("({}, {})", tile_pos.x, tile_pos.y),
+        };
+        // Display text slightly below the cursor, in window logical pixels
+        node.left = Val::Px(cursor_position.x + 12.);
+        node.top = Val::Px(cursor_position.y + 12.);
+        *visibility = Visibility::Visible;
+        return;
+    }
+}
@@ -1,10 +1,11 @@
 //! Events related to Tiled map loading
 //!
-//! These events will be fired after the whole map has loaded.
+//! Creation events will be fired after the whole map has loaded.
+//! Despawn events will be fired before the corresponding entities are despawned.
 //! More informations in the [dedicated example](https://github.com/adrien-bon/bevy_ecs_tiled/blob/main/examples/map_events.rs)
 
 use crate::prelude::*;
-use bevy::prelude::*;
+use bevy::{asset::AssetPath, prelude::*};
 use bevy_ecs_tilemap::prelude::*;
 use tiled::{Layer, LayerTile, Map, Object};
 
@@ -15,6 +16,8 @@ pub struct TiledMapCreated {
     pub map: Entity,
     /// Handle to the loaded Tiled Map
     pub map_handle: Handle<TiledMap>,
+    /// Whether the map was already spawned before, for instance when it is reloaded
+    pub respawn: bool,
 }
 
 impl<'a> TiledMapCreated {
@@ -35,6 +38,10 @@ pub struct TiledLayerCreated {
     pub map_handle: Handle<TiledMap>,
     /// Layer ID
     pub layer_id: usize,
+    /// Layer name
+    pub name: String,
+    /// Whether the map was already spawned before, for instance when it is reloaded
+    pub respawn: bool,
 }
 
 impl<'a> TiledLayerCreated {
@@ -64,10 +71,19 @@ pub struct TiledObjectCreated {
     pub layer_id: usize,
     /// Object ID
     pub object_id: usize,
+    /// Object name
+    pub name: String,
+    /// Object class
+    pub class: String,
 }
 
 impl TiledObjectCreated {
-    pub fn from_layer(layer: &TiledLayerCreated, object: Entity, object_id: usize) -> Self {
+    pub fn from_layer(
+        layer: &TiledLayerCreated,
+        object: Entity,
+        object_id: usize,
+        object_data: &Object,
+    ) -> Self {
         Self {
             map: layer.map,
             layer: layer.layer,
@@ -75,6 +91,8 @@ impl TiledObjectCreated {
             map_handle: layer.map_handle.clone(),
             object,
             object_id,
+            name: object_data.name.clone(),
+            class: object_data.user_type.clone(),
         }
     }
 }
@@ -183,3 +201,140 @@ impl<'a> TiledSpecialTileCreated {
             .center_in_world(&get_grid_size(map), &get_map_type(map))
     }
 }
+
+/// Event sent when a Tiled map could not be spawned because it or one of its dependencies failed to load
+#[derive(Event, Clone, Debug)]
+pub struct TiledMapLoadFailed {
+    /// Map [Entity]
+    pub map: Entity,
+    /// Handle to the Tiled Map
+    pub map_handle: Handle<TiledMap>,
+    /// Paths of the assets which failed to load, if they could be determined
+    pub missing: Vec<AssetPath<'static>>,
+}
+
+/// Event sent before a Tiled map is despawned, when its asset has been removed or using [DespawnTiledMap].
+///
+/// [TiledLayerDespawned] and [TiledObjectDespawned] events are sent for all its layers and
+/// objects beforehand: all these entities can still be accessed by observers.
+#[derive(Event, Clone, Debug)]
+pub struct TiledMapDespawned {
+    /// Spawned map [Entity]
+    pub map: Entity,
+    /// [AssetId] of the [TiledMap]
+    pub map_asset_id: AssetId<TiledMap>,
+}
+
+/// Event sent before a Tiled layer is despawned, for instance when reloading its map
+#[derive(Event, Clone, Debug)]
+pub struct TiledLayerDespawned {
+    /// Spawned map [Entity]
+    pub map: Entity,
+    /// Spawned layer [Entity]
+    pub layer: Entity,
+    /// Tiled ID of the layer
+    pub tiled_id: u32,
+}
+
+/// Event sent before a Tiled object is despawned, for instance when reloading its map
+#[derive(Event, Clone, Debug)]
+pub struct TiledObjectDespawned {
+    /// Spawned map [Entity]
+    pub map: Entity,
+    /// Spawned object [Entity]
+    pub object: Entity,
+    /// Tiled ID of the object
+    pub tiled_id: u32,
+}
+
+/// Kind of Tiled entity custom properties are inserted to, with its Tiled ID
+#[cfg(feature = "user_properties")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TiledPropertiesTarget {
+    /// Tiled map
+    Map,
+    /// Tiled layer, with its Tiled ID
+    Layer(u32),
+    /// Tiled object, with its Tiled ID
+    Object(u32),
+    /// Tiled tile, with its tileset name and tile ID
+    Tile(String, tiled::TileId),
+}
+
+/// Event sent just before custom properties are inserted on a Tiled entity
+///
+/// Observers of this event can insert their own components on the entity:
+/// custom properties will be inserted afterward, so they can override them.
+/// Note that this event is sent for all entities, even the ones without custom properties.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn main() {
+///     App::new().add_observer(
+///         |trigger: Trigger<TiledPropertiesPreInsert>,
+///          mut commands: Commands,
+///          q_names: Query<&Name>| {
+///             let TiledPropertiesTarget::Object(_) = trigger.event().target else {
+///                 return;
+///             };
+///             let entity = trigger.event().entity;
+///             if q_names
+///                 .get(entity)
+///                 .is_ok_and(|name| name.as_str().starts_with("Object(enemy_"))
+///             {
+///                 commands.entity(entity).insert(Enemy);
+///             }
+///         },
+///     );
+/// }
+/// ```
+#[cfg(feature = "user_properties")]
+#[derive(Event, Clone, Debug)]
+pub struct TiledPropertiesPreInsert {
+    /// Spawned map [Entity]
+    pub map: Entity,
+    /// [Entity] custom properties will be inserted to
+    pub entity: Entity,
+    /// Kind and Tiled ID of the entity
+    pub target: TiledPropertiesTarget,
+}
+
+/// Event sent once custom properties have been inserted on all entities of a Tiled map
+///
+/// Observers of this event can query components deserialized from custom properties,
+/// for any entity of the map.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// #[derive(Component, Default, Reflect)]
+/// #[reflect(Component, Default)]
+/// struct SpawnPoint;
+///
+/// fn main() {
+///     App::new().add_observer(
+///         |trigger: Trigger<TiledPropertiesHydrated>,
+///          q_spawns: Query<(Entity, &TiledMapReference), With<SpawnPoint>>| {
+///             for (entity, map) in q_spawns.iter() {
+///                 if map.0 == trigger.event().map {
+///                     info!("Found spawn point {entity:?}");
+///                 }
+///             }
+///         },
+///     );
+/// }
+/// ```
+#[cfg(feature = "user_properties")]
+#[derive(Event, Clone, Debug)]
+pub struct TiledPropertiesHydrated {
+    /// Spawned map [Entity]
+    pub map: Entity,
+}
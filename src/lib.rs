@@ -10,12 +10,15 @@
 //!
 #![doc = include_str!("../book/src/getting-started.md")]
 
+pub mod animation;
 pub mod asset;
 pub mod components;
 pub mod events;
 pub mod loader;
 pub mod names;
+pub mod picking;
 pub mod utils;
+pub mod writer;
 
 #[cfg(feature = "debug")]
 pub mod debug;
@@ -28,8 +31,13 @@ pub mod properties;
 
 /// `bevy_ecs_tiled` public exports.
 pub mod prelude {
+    pub use super::DespawnTiledMap;
+    #[cfg(feature = "user_properties")]
+    pub use super::ExportTiledTypes;
+    pub use super::SpawnTiledMap;
     pub use super::TiledMapHandle;
     pub use super::TiledMapPlugin;
+    pub use crate::animation::*;
     pub use crate::asset::*;
     pub use crate::components::*;
     #[cfg(feature = "debug")]
@@ -38,11 +46,18 @@ pub mod prelude {
     pub use crate::names::*;
     #[cfg(feature = "physics")]
     pub use crate::physics::prelude::*;
+    pub use crate::picking::*;
+    #[cfg(feature = "user_properties")]
+    pub use crate::properties::{TiledClassColor, TiledColorFormat, TiledFlags, TiledSkip};
     pub use crate::utils::*;
 }
 
 use crate::prelude::*;
-use bevy::{asset::RecursiveDependencyLoadState, prelude::*};
+use bevy::{
+    asset::{AssetPath, LoadState, RecursiveDependencyLoadState},
+    ecs::world::Command,
+    prelude::*,
+};
 use bevy_ecs_tilemap::prelude::*;
 use std::{env, path::PathBuf};
 
@@ -59,6 +74,130 @@ use std::{env, path::PathBuf};
 )]
 pub struct TiledMapHandle(pub Handle<TiledMap>);
 
+/// [Command] to spawn a Tiled map.
+///
+/// The map entity is spawned with a [RespawnTiledMap] marker, so it will be
+/// processed until its [TiledMap] asset is fully loaded, no matter how long it takes
+/// and even if the asset was already loaded before the command was applied.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.queue(
+///         SpawnTiledMap::new(asset_server.load("finite.tmx"))
+///             .with_transform(Transform::from_xyz(100., 0., 0.)),
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SpawnTiledMap {
+    /// [Handle] to the [TiledMap] to spawn.
+    pub handle: Handle<TiledMap>,
+    /// [Transform] of the map entity.
+    pub transform: Transform,
+    /// Settings of the map.
+    pub settings: TiledMapSettings,
+}
+
+impl SpawnTiledMap {
+    /// Create a new [SpawnTiledMap] command with default [Transform] and [TiledMapSettings].
+    pub fn new(handle: Handle<TiledMap>) -> Self {
+        Self {
+            handle,
+            transform: Transform::default(),
+            settings: TiledMapSettings::default(),
+        }
+    }
+
+    /// Set the [Transform] of the map entity.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Set the [TiledMapSettings] of the map entity.
+    pub fn with_settings(mut self, settings: TiledMapSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+impl Command for SpawnTiledMap {
+    fn apply(self, world: &mut World) {
+        world.spawn((
+            TiledMapHandle(self.handle),
+            self.transform,
+            self.settings,
+            RespawnTiledMap,
+        ));
+    }
+}
+
+/// [Command] to despawn a Tiled map and all its layers.
+///
+/// [TiledObjectDespawned], [TiledLayerDespawned] and [TiledMapDespawned] events are sent
+/// before the entities are actually despawned, so observers can still access them.
+/// Note that these events are not sent if the map [Entity] is directly despawned.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn despawn(mut commands: Commands, q_maps: Query<Entity, With<TiledMapMarker>>) {
+///     for map in q_maps.iter() {
+///         commands.queue(DespawnTiledMap(map));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DespawnTiledMap(pub Entity);
+
+impl Command for DespawnTiledMap {
+    fn apply(self, world: &mut World) {
+        let map_entity = self.0;
+        let Some(map_asset_id) = world
+            .get::<TiledMapHandle>(map_entity)
+            .map(|handle| handle.0.id())
+        else {
+            return;
+        };
+        let (objects, layers) = world
+            .get::<TiledIdStorage>(map_entity)
+            .map(|storage| {
+                (
+                    storage.objects.clone().into_iter().collect::<Vec<_>>(),
+                    storage.layers.clone().into_iter().collect::<Vec<_>>(),
+                )
+            })
+            .unwrap_or_default();
+        for (tiled_id, object) in objects {
+            world.trigger(TiledObjectDespawned {
+                map: map_entity,
+                object,
+                tiled_id,
+            });
+        }
+        for (tiled_id, layer) in layers {
+            world.trigger(TiledLayerDespawned {
+                map: map_entity,
+                layer,
+                tiled_id,
+            });
+        }
+        world.trigger(TiledMapDespawned {
+            map: map_entity,
+            map_asset_id,
+        });
+        if world.entities().contains(map_entity) {
+            world.entity_mut(map_entity).despawn_recursive();
+        }
+    }
+}
+
 /// [TiledMapPlugin] [Plugin] global configuration.
 #[allow(dead_code)]
 #[derive(Resource, Clone)]
@@ -67,6 +206,37 @@ pub struct TiledMapPluginConfig {
     ///
     /// If [None], will not export Tiled types at startup.
     pub tiled_types_export_file: Option<PathBuf>,
+    /// Name of the object custom property to use as the object entity [Name].
+    ///
+    /// If [None] or if an object does not have this property (as a string),
+    /// its [Name] will be `Object(<object name>)`.
+    pub object_name_property: Option<String>,
+    /// Name of the layer custom property to use as the layer Z-position.
+    ///
+    /// If [None] or if a layer does not have this property (as a float or an int),
+    /// its Z-position will be computed using [TiledMapSettings::layer_z_offset].
+    pub layer_z_property: Option<String>,
+    /// Name of the object custom property to use as the object Z-position, relative to its layer.
+    ///
+    /// If [None] or if an object does not have this property (as a float or an int),
+    /// its Z-position will be 0.
+    pub object_z_property: Option<String>,
+    /// Name of the map custom property to use as the map [LayerPositioning].
+    ///
+    /// Property should be a string, either `TiledOffset` or `Centered`.
+    /// It is only used when [TiledMapSettings::layer_positioning] is left to its default value,
+    /// see [TiledMap::layer_positioning].
+    /// Property is read when the map asset is loaded, this setting must be set before.
+    pub layer_positioning_property: Option<String>,
+    /// Path to a `.tiled-project` file or to a Tiled types JSON file.
+    ///
+    /// If set, maps will be checked when loaded: a warning will be emitted for each class
+    /// used in a map which is not declared in this file or not registered in the `AppTypeRegistry`.
+    #[cfg(feature = "user_properties")]
+    pub tiled_project_file: Option<PathBuf>,
+    /// Format used for `Color` custom properties, both for export and loading.
+    #[cfg(feature = "user_properties")]
+    pub color_format: properties::TiledColorFormat,
 }
 
 impl Default for TiledMapPluginConfig {
@@ -75,6 +245,14 @@ impl Default for TiledMapPluginConfig {
         path.push("tiled_types_export.json");
         Self {
             tiled_types_export_file: Some(path),
+            object_name_property: None,
+            layer_z_property: None,
+            object_z_property: None,
+            layer_positioning_property: None,
+            #[cfg(feature = "user_properties")]
+            tiled_project_file: None,
+            #[cfg(feature = "user_properties")]
+            color_format: properties::TiledColorFormat::default(),
         }
     }
 }
@@ -96,32 +274,115 @@ pub struct TiledMapPlugin(pub TiledMapPluginConfig);
 
 impl Plugin for TiledMapPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.init_asset::<TiledMap>()
+        // Configuration must be available before creating the asset loader
+        app.insert_resource(self.0.clone())
+            .init_asset::<TiledMap>()
             .init_asset_loader::<TiledLoader>()
-            .add_systems(Update, (handle_map_events, process_loaded_maps))
-            .insert_resource(self.0.clone());
+            .register_type::<TiledTileSource>()
+            .register_type::<TiledTileProbability>()
+            .register_type::<TiledLayerId>()
+            .register_type::<TiledObjectId>()
+            .register_type::<TiledLayerParallax>()
+            .add_systems(
+                Update,
+                (
+                    handle_map_events,
+                    process_loaded_maps,
+                    update_map_positioning,
+                    update_layers_color,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                (y_sort_objects, apply_layers_parallax).before(TransformSystem::TransformPropagate),
+            );
+
+        // Make sure common math types are available as nested Tiled classes
+        #[cfg(feature = "user_properties")]
+        app.register_type::<Vec2>()
+            .register_type::<Vec3>()
+            .register_type::<IVec2>()
+            .register_type::<UVec2>();
 
         #[cfg(feature = "user_properties")]
-        app.add_systems(Startup, export_types);
+        app.add_event::<ExportTiledTypes>()
+            .add_systems(Startup, export_types)
+            .add_systems(Update, export_types_on_demand);
     }
 }
 
+/// [Event] to export Tiled types on demand.
+///
+/// Can be used to re-export Tiled types at runtime, for instance after registering new types.
+/// Startup export is still controlled by [TiledMapPluginConfig::tiled_types_export_file].
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn export(mut events: EventWriter<ExportTiledTypes>) {
+///     events.send(ExportTiledTypes {
+///         path: "tiled_types_export.json".into(),
+///     });
+/// }
+/// ```
+#[cfg(feature = "user_properties")]
+#[derive(Event, Clone, Debug)]
+pub struct ExportTiledTypes {
+    /// Path to the Tiled types export file.
+    pub path: PathBuf,
+}
+
 #[cfg(feature = "user_properties")]
 fn export_types(reg: Res<AppTypeRegistry>, config: Res<TiledMapPluginConfig>) {
-    use std::{fs::File, io::BufWriter, ops::Deref};
+    use std::ops::Deref;
     if let Some(path) = &config.tiled_types_export_file {
-        info!("Export Tiled types to '{:?}'", path);
-        let file = File::create(path).unwrap();
-        let writer = BufWriter::new(file);
-        let registry = properties::export::TypeExportRegistry::from_registry(reg.0.read().deref());
-        serde_json::to_writer_pretty(writer, &registry.to_vec()).unwrap();
+        write_types(reg.0.read().deref(), path, config.color_format).unwrap();
+    }
+}
+
+#[cfg(feature = "user_properties")]
+fn export_types_on_demand(
+    reg: Res<AppTypeRegistry>,
+    config: Res<TiledMapPluginConfig>,
+    mut events: EventReader<ExportTiledTypes>,
+) {
+    use std::ops::Deref;
+    for event in events.read() {
+        if let Err(err) = write_types(reg.0.read().deref(), &event.path, config.color_format) {
+            error!(
+                "Could not export Tiled types to '{:?}': {}",
+                event.path, err
+            );
+        }
     }
 }
 
+#[cfg(feature = "user_properties")]
+fn write_types(
+    registry: &bevy::reflect::TypeRegistry,
+    path: &std::path::Path,
+    color_format: properties::TiledColorFormat,
+) -> std::io::Result<()> {
+    use std::{fs::File, io::BufWriter};
+    info!("Export Tiled types to '{:?}'", path);
+    let file = File::create(path)?;
+    let writer = BufWriter::new(file);
+    let registry = properties::export::TypeExportRegistry::from_registry_with_color_format(
+        registry,
+        color_format,
+    );
+    serde_json::to_writer_pretty(writer, &registry.to_vec())?;
+    Ok(())
+}
+
 /// System to spawn a map once it has been fully loaded.
 #[allow(clippy::type_complexity)]
 fn process_loaded_maps(
     asset_server: Res<AssetServer>,
+    config: Res<TiledMapPluginConfig>,
     mut commands: Commands,
     maps: ResMut<Assets<TiledMap>>,
     mut map_query: Query<
@@ -131,51 +392,277 @@ fn process_loaded_maps(
             &mut TiledIdStorage,
             &TilemapRenderSettings,
             &TiledMapSettings,
+            Has<TiledMapMarker>,
+            Has<TiledMapFlipX>,
+            Has<TiledMapFlipY>,
         ),
         Or<(Changed<TiledMapHandle>, With<RespawnTiledMap>)>,
     >,
 ) {
-    for (map_entity, map_handle, mut tiled_id_storage, render_settings, tiled_settings) in
-        map_query.iter_mut()
+    for (
+        map_entity,
+        map_handle,
+        mut tiled_id_storage,
+        render_settings,
+        tiled_settings,
+        already_spawned,
+        flip_x,
+        flip_y,
+    ) in map_query.iter_mut()
     {
-        if let Some(load_state) = asset_server.get_recursive_dependency_load_state(&map_handle.0) {
-            if !load_state.is_loaded() {
-                if let RecursiveDependencyLoadState::Failed(err) = load_state {
-                    error!("Error loading map: {}", err);
-                    return;
-                }
-                // If not fully loaded yet, insert the 'Respawn' marker so we will try to load it at next frame
-                commands.entity(map_entity).insert(RespawnTiledMap);
-                debug!(
-                    "Map '{}' is not fully loaded yet...",
-                    map_handle.0.path().unwrap()
-                );
-                continue;
-            }
-
-            let tiled_map = maps.get(&map_handle.0).unwrap();
-            info!(
-                "Map '{}' has finished loading, spawn it",
-                map_handle.0.path().unwrap()
+        let load_state = asset_server.get_recursive_dependency_load_state(&map_handle.0);
+        if let Some(RecursiveDependencyLoadState::Failed(err)) = &load_state {
+            // A dependency permanently failed to load: do not try to spawn the map again
+            let missing = failed_dependencies(&asset_server, &maps, &map_handle.0);
+            error!(
+                "Error loading map '{:?}': {} (failed assets: {:?})",
+                map_handle.0.path(),
+                err,
+                missing
             );
+            commands.trigger(TiledMapLoadFailed {
+                map: map_entity,
+                map_handle: map_handle.0.clone(),
+                missing,
+            });
+            commands.entity(map_entity).remove::<RespawnTiledMap>();
+            continue;
+        }
+
+        // Assets which were directly added to Assets<TiledMap> are not tracked by the AssetServer
+        let Some(tiled_map) = maps
+            .get(&map_handle.0)
+            .filter(|_| load_state.as_ref().is_none_or(|s| s.is_loaded()))
+        else {
+            // If not fully loaded yet, insert the 'Respawn' marker so we will try to load it at next frame
+            commands.entity(map_entity).insert(RespawnTiledMap);
+            debug!("Map '{:?}' is not fully loaded yet...", map_handle.0.path());
+            continue;
+        };
+
+        info!(
+            "Map '{:?}' has finished loading, spawn it",
+            map_handle.0.path()
+        );
+
+        // Clean map layers
+        remove_layers(&mut commands, map_entity, &mut tiled_id_storage);
+
+        debug!("Spawn map layers");
+        loader::load_map(
+            &mut commands,
+            map_entity,
+            &map_handle.0,
+            tiled_map,
+            &mut tiled_id_storage,
+            render_settings,
+            tiled_settings,
+            &config,
+            &asset_server,
+            already_spawned,
+            BVec2::new(flip_x, flip_y),
+        );
 
-            // Clean map layers
-            remove_layers(&mut commands, &mut tiled_id_storage);
+        // Remove the respawn marker
+        commands.entity(map_entity).remove::<RespawnTiledMap>();
+    }
+}
+
+/// Retrieve the paths of a map or of its tilesets images which failed to load.
+fn failed_dependencies(
+    asset_server: &AssetServer,
+    maps: &Assets<TiledMap>,
+    map_handle: &Handle<TiledMap>,
+) -> Vec<AssetPath<'static>> {
+    let Some(tiled_map) = maps.get(map_handle) else {
+        // The map itself could not be loaded
+        return map_handle.path().cloned().into_iter().collect();
+    };
+    tiled_map
+        .tilemap_textures
+        .values()
+        .flat_map(|texture| texture.image_handles())
+        .filter(|handle| {
+            matches!(
+                asset_server.get_load_state(handle.id()),
+                Some(LoadState::Failed(_))
+            )
+        })
+        .filter_map(|handle| handle.path().cloned())
+        .collect()
+}
 
-            debug!("Spawn map layers");
-            loader::load_map(
-                &mut commands,
-                map_entity,
-                &map_handle.0,
+/// System to update layers [Transform] in place when [TiledMapSettings] change,
+/// instead of respawning the whole map.
+fn update_map_positioning(
+    config: Res<TiledMapPluginConfig>,
+    maps: Res<Assets<TiledMap>>,
+    q_maps: Query<
+        (
+            Entity,
+            &TiledMapHandle,
+            &TiledMapSettings,
+            Has<TiledMapFlipX>,
+            Has<TiledMapFlipY>,
+        ),
+        Changed<TiledMapSettings>,
+    >,
+    mut q_layers: Query<
+        (
+            &TiledMapReference,
+            &TiledLayerId,
+            &mut Transform,
+            Option<&mut TiledLayerParallax>,
+        ),
+        With<TiledMapLayer>,
+    >,
+) {
+    for (map_entity, map_handle, tiled_settings, flip_x, flip_y) in q_maps.iter() {
+        let Some(tiled_map) = maps.get(&map_handle.0) else {
+            continue;
+        };
+        let flip = loader::map_flip(&tiled_map.map, BVec2::new(flip_x, flip_y));
+        for (map_ref, layer_id, mut transform, parallax) in q_layers.iter_mut() {
+            if map_ref.0 != map_entity {
+                continue;
+            }
+            let Some((layer_index, layer)) = tiled_map
+                .map
+                .layers()
+                .enumerate()
+                .find(|(_, layer)| layer.id() == layer_id.0)
+            else {
+                continue;
+            };
+            *transform = loader::layer_transform(
                 tiled_map,
-                &mut tiled_id_storage,
-                render_settings,
+                layer_index,
+                &layer,
                 tiled_settings,
-                &asset_server,
+                &config,
+                flip,
             );
+            if let Some(mut parallax) = parallax {
+                parallax.base_position = transform.translation.truncate();
+                parallax.origin = loader::parallax_origin(tiled_map, tiled_settings, flip);
+            }
+        }
+    }
+}
 
-            // Remove the respawn marker
-            commands.entity(map_entity).remove::<RespawnTiledMap>();
+/// System to apply layers tint and opacity, as well as map tint, to tiles and images.
+#[allow(clippy::type_complexity)]
+fn update_layers_color(
+    q_maps: Query<Entity, Changed<TiledMapTint>>,
+    mut removed_tints: RemovedComponents<TiledMapTint>,
+    mut removed_opacities: RemovedComponents<TiledLayerOpacity>,
+    q_tints: Query<&TiledMapTint>,
+    q_layers: Query<(
+        Entity,
+        &Children,
+        &TiledMapReference,
+        Ref<TiledLayerAuthoredColor>,
+        Option<Ref<TiledLayerOpacity>>,
+    )>,
+    q_tilemaps: Query<&TileStorage>,
+    mut q_tiles: Query<&mut TileColor>,
+    mut q_images: Query<&mut Sprite, With<TiledMapImage>>,
+) {
+    let changed_maps = q_maps
+        .iter()
+        .chain(removed_tints.read())
+        .collect::<Vec<_>>();
+    // Restore authored opacity of layers whose opacity was removed
+    let changed_layers = removed_opacities.read().collect::<Vec<_>>();
+    for (layer_entity, children, map_ref, authored, opacity) in q_layers.iter() {
+        if !authored.is_added()
+            && !opacity.as_ref().is_some_and(|o| o.is_changed())
+            && !changed_maps.contains(&map_ref.0)
+            && !changed_layers.contains(&layer_entity)
+        {
+            continue;
+        }
+        let tint = authored.tint.to_srgba();
+        let map_tint = q_tints
+            .get(map_ref.0)
+            .map_or(Color::WHITE, |t| t.0)
+            .to_srgba();
+        let color = Color::srgba(
+            tint.red * map_tint.red,
+            tint.green * map_tint.green,
+            tint.blue * map_tint.blue,
+            tint.alpha * map_tint.alpha * authored.opacity * opacity.map_or(1., |o| o.0),
+        );
+        for &child in children.iter() {
+            if let Ok(storage) = q_tilemaps.get(child) {
+                for tile in storage.iter().flatten() {
+                    if let Ok(mut tile_color) = q_tiles.get_mut(*tile) {
+                        tile_color.0 = color;
+                    }
+                }
+            }
+            if let Ok(mut sprite) = q_images.get_mut(child) {
+                sprite.color = color;
+            }
+        }
+    }
+}
+
+/// System to update objects Z-position when y-sort is enabled.
+fn y_sort_objects(
+    q_y_sort: Query<&TiledYSort>,
+    q_layers: Query<&GlobalTransform, With<TiledMapObjectLayer>>,
+    mut q_objects: Query<(&Parent, &TiledMapReference, &mut Transform), With<TiledMapObject>>,
+) {
+    // Do not iterate over objects if y-sort is not used at all
+    if q_y_sort.is_empty() {
+        return;
+    }
+    for (parent, map_ref, mut transform) in q_objects.iter_mut() {
+        let Some(y_sort) = q_y_sort
+            .get(parent.get())
+            .or_else(|_| q_y_sort.get(map_ref.0))
+            .ok()
+        else {
+            continue;
+        };
+        let Ok(layer_transform) = q_layers.get(parent.get()) else {
+            continue;
+        };
+        let z = -layer_transform.transform_point(transform.translation).y * y_sort.scale;
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
+
+/// System to offset layers with a parallax factor according to the camera position.
+fn apply_layers_parallax(
+    q_camera: Query<(&Camera, &Transform), Without<TiledLayerParallax>>,
+    q_maps: Query<&GlobalTransform, With<TiledMapHandle>>,
+    mut q_layers: Query<(&TiledMapReference, &TiledLayerParallax, &mut Transform)>,
+) {
+    if q_layers.is_empty() {
+        return;
+    }
+    let Some((_, camera_transform)) = q_camera.iter().find(|(camera, _)| camera.is_active) else {
+        return;
+    };
+    for (map_ref, parallax, mut transform) in q_layers.iter_mut() {
+        let Ok(map_transform) = q_maps.get(map_ref.0) else {
+            continue;
+        };
+        // Camera position relative to the map
+        let camera_position = map_transform
+            .affine()
+            .inverse()
+            .transform_point3(camera_transform.translation)
+            .truncate();
+        let position = parallax.base_position
+            + (camera_position - parallax.origin)
+                * Vec2::new(1. - parallax.parallax_x, 1. - parallax.parallax_y);
+        if transform.translation.truncate() != position {
+            transform.translation = position.extend(transform.translation.z);
         }
     }
 }
@@ -184,7 +671,7 @@ fn process_loaded_maps(
 fn handle_map_events(
     mut commands: Commands,
     mut map_events: EventReader<AssetEvent<TiledMap>>,
-    mut map_query: Query<(Entity, &TiledMapHandle)>,
+    map_query: Query<(Entity, &TiledMapHandle)>,
 ) {
     for event in map_events.read() {
         match event {
@@ -198,9 +685,9 @@ fn handle_map_events(
             }
             AssetEvent::Removed { id } => {
                 info!("Map removed: {id}");
-                for (map_entity, map_handle) in map_query.iter_mut() {
+                for (map_entity, map_handle) in map_query.iter() {
                     if map_handle.0.id() == *id {
-                        commands.entity(map_entity).despawn_recursive();
+                        commands.queue(DespawnTiledMap(map_entity));
                     }
                 }
             }
@@ -209,11 +696,167 @@ fn handle_map_events(
     }
 }
 
-fn remove_layers(commands: &mut Commands, tiled_id_storage: &mut TiledIdStorage) {
+fn remove_layers(
+    commands: &mut Commands,
+    map_entity: Entity,
+    tiled_id_storage: &mut TiledIdStorage,
+) {
+    trigger_layers_despawned(commands, map_entity, tiled_id_storage);
     for layer_entity in tiled_id_storage.layers.values() {
         commands.entity(*layer_entity).despawn_recursive();
     }
     tiled_id_storage.layers.clear();
     tiled_id_storage.objects.clear();
     tiled_id_storage.tiles.clear();
+    #[cfg(feature = "user_properties")]
+    tiled_id_storage.classes.clear();
+}
+
+fn trigger_layers_despawned(
+    commands: &mut Commands,
+    map_entity: Entity,
+    tiled_id_storage: &TiledIdStorage,
+) {
+    for (&tiled_id, &object) in tiled_id_storage.objects.iter() {
+        commands.trigger(TiledObjectDespawned {
+            map: map_entity,
+            object,
+            tiled_id,
+        });
+    }
+    for (&tiled_id, &layer) in tiled_id_storage.layers.iter() {
+        commands.trigger(TiledLayerDespawned {
+            map: map_entity,
+            layer,
+            tiled_id,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::AssetSourceId;
+    use std::path::Path;
+
+    #[test]
+    fn restore_layer_opacity() {
+        let mut app = App::new();
+        app.add_systems(Update, update_layers_color);
+
+        let world = app.world_mut();
+        let map = world.spawn_empty().id();
+        let tile = world.spawn(TileColor::default()).id();
+        let mut storage = TileStorage::empty(TilemapSize::new(1, 1));
+        storage.set(&TilePos::new(0, 0), tile);
+        let tilemap = world.spawn(storage).id();
+        let layer = world
+            .spawn((
+                TiledMapReference(map),
+                TiledLayerAuthoredColor {
+                    tint: Color::WHITE,
+                    opacity: 0.8,
+                },
+            ))
+            .add_child(tilemap)
+            .id();
+        let alpha = |app: &App| app.world().get::<TileColor>(tile).unwrap().0.alpha();
+
+        app.update();
+        assert_eq!(alpha(&app), 0.8);
+
+        app.world_mut()
+            .entity_mut(layer)
+            .insert(TiledLayerOpacity(0.5));
+        app.update();
+        assert_eq!(alpha(&app), 0.4);
+
+        app.world_mut()
+            .entity_mut(layer)
+            .remove::<TiledLayerOpacity>();
+        app.update();
+        assert_eq!(alpha(&app), 0.8);
+    }
+
+    #[derive(Resource, Default)]
+    struct DespawnedCount {
+        maps: usize,
+        layers: usize,
+        objects: usize,
+    }
+
+    #[test]
+    fn despawn_map_command() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            TiledMapPlugin(TiledMapPluginConfig {
+                tiled_types_export_file: None,
+                ..default()
+            }),
+        ))
+        .init_asset::<Image>()
+        .init_resource::<DespawnedCount>()
+        // Only count events whose entities can still be accessed
+        .add_observer(
+            |trigger: Trigger<TiledMapDespawned>,
+             q_maps: Query<&TiledMapMarker>,
+             mut count: ResMut<DespawnedCount>| {
+                if q_maps.contains(trigger.event().map) {
+                    count.maps += 1;
+                }
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<TiledLayerDespawned>,
+             q_layers: Query<&TiledMapLayer>,
+             mut count: ResMut<DespawnedCount>| {
+                if q_layers.contains(trigger.event().layer) {
+                    count.layers += 1;
+                }
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<TiledObjectDespawned>,
+             q_objects: Query<&TiledMapObject>,
+             mut count: ResMut<DespawnedCount>| {
+                if q_objects.contains(trigger.event().object) {
+                    count.objects += 1;
+                }
+            },
+        );
+
+        let tiled_map = TiledMap::from_bytes(
+            &std::fs::read("assets/finite.tmx").unwrap(),
+            Path::new("finite.tmx"),
+            TiledMapDependencies::AssetServer(
+                app.world().resource::<AssetServer>(),
+                AssetSourceId::Default,
+            ),
+            &TiledMapBytesOptions::new().with_file(
+                "Tileset1.tsx",
+                std::fs::read("assets/Tileset1.tsx").unwrap(),
+            ),
+        )
+        .unwrap();
+        let map_handle = app
+            .world_mut()
+            .resource_mut::<Assets<TiledMap>>()
+            .add(tiled_map);
+        let map_entity = app.world_mut().spawn(TiledMapHandle(map_handle)).id();
+        app.update();
+
+        let storage = app.world().get::<TiledIdStorage>(map_entity).unwrap();
+        let (layers, objects) = (storage.layers.len(), storage.objects.len());
+        assert!(layers > 0 && objects > 0);
+
+        DespawnTiledMap(map_entity).apply(app.world_mut());
+        assert!(!app.world().entities().contains(map_entity));
+
+        let count = app.world().resource::<DespawnedCount>();
+        assert_eq!(count.maps, 1);
+        assert_eq!(count.layers, layers);
+        assert_eq!(count.objects, objects);
+    }
 }
@@ -31,14 +31,102 @@
 #[cfg(feature = "user_properties")]
 use crate::properties::command::PropertiesCommandExt;
 
-use crate::prelude::*;
-use bevy::{prelude::*, utils::HashMap};
+use crate::{
+    asset::{infinite_layer_bounds, tiled_asset_path},
+    prelude::*,
+    TiledMapPluginConfig,
+};
+use bevy::{asset::AssetSourceId, prelude::*, utils::HashMap};
 use bevy_ecs_tilemap::prelude::*;
 use tiled::{
     ChunkData, FiniteTileLayer, ImageLayer, InfiniteTileLayer, Layer, LayerType, ObjectLayer, Tile,
     TileId, TileLayer,
 };
 
+/// Compute the [Transform] of a layer, relative to the map.
+pub(super) fn layer_transform(
+    tiled_map: &TiledMap,
+    layer_index: usize,
+    layer: &Layer,
+    tiled_settings: &TiledMapSettings,
+    config: &TiledMapPluginConfig,
+    flip: BVec2,
+) -> Transform {
+    // Order of the differents layers in the .TMX file is important:
+    // a layer appearing last in the .TMX should appear "on top" of previous layers
+    // Start with a negative offset so the upper layer will be at Z-offset = 0
+    let offset_z = tiled_map.map.layers().len() as f32 * (-100.0)
+        + (layer_index + 1) as f32 * tiled_settings.layer_z_offset;
+
+    // Z-offset can be overridden using a layer property
+    let layer_z = match config
+        .layer_z_property
+        .as_ref()
+        .and_then(|property| layer.properties.get(property))
+    {
+        Some(tiled::PropertyValue::FloatValue(z)) => *z,
+        Some(tiled::PropertyValue::IntValue(z)) => *z as f32,
+        _ => offset_z,
+    };
+
+    // Apply layer offset and MapPositioning setting
+    let offset_x = if flip.x {
+        -layer.offset_x
+    } else {
+        layer.offset_x
+    };
+    let offset_y = if flip.y {
+        -layer.offset_y
+    } else {
+        layer.offset_y
+    };
+    let offset_transform = Transform::from_xyz(offset_x, -offset_y, layer_z);
+    tiled_map.positioning_transform(tiled_settings) * offset_transform
+}
+
+/// Compute the map parallax origin, relative to the map.
+pub(super) fn parallax_origin(
+    tiled_map: &TiledMap,
+    tiled_settings: &TiledMapSettings,
+    flip: BVec2,
+) -> Vec2 {
+    let origin = from_tiled_coords_to_bevy(
+        flip_tiled_position(&tiled_map.map, tiled_map.parallax_origin, flip),
+        &get_map_type(&tiled_map.map),
+        &get_map_size(&tiled_map.map),
+        &get_grid_size(&tiled_map.map),
+    );
+    tiled_map
+        .positioning_transform(tiled_settings)
+        .transform_point(origin.extend(0.))
+        .truncate()
+}
+
+/// Axes along which a map can actually be mirrored, from [TiledMapFlipX] and [TiledMapFlipY].
+///
+/// Only finite orthogonal maps can be mirrored: on other maps, mirroring the tiles grid
+/// does not match a mirror of tiles images and objects on screen.
+pub(super) fn map_flip(map: &tiled::Map, flip: BVec2) -> BVec2 {
+    if map.infinite() || map.orientation != tiled::Orientation::Orthogonal {
+        return BVec2::FALSE;
+    }
+    flip
+}
+
+/// Size of an orthogonal map in Tiled coordinates.
+fn tiled_map_extent(map: &tiled::Map) -> Vec2 {
+    let grid_size = get_grid_size(map);
+    Vec2::new(
+        map.width as f32 * grid_size.x,
+        map.height as f32 * grid_size.y,
+    )
+}
+
+/// Mirror a position in Tiled coordinates along the map center axes.
+fn flip_tiled_position(map: &tiled::Map, position: Vec2, flip: BVec2) -> Vec2 {
+    Vec2::select(flip, tiled_map_extent(map) - position, position)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn load_map(
     commands: &mut Commands,
@@ -48,7 +136,10 @@ pub(super) fn load_map(
     tiled_id_storage: &mut TiledIdStorage,
     render_settings: &TilemapRenderSettings,
     tiled_settings: &TiledMapSettings,
+    config: &TiledMapPluginConfig,
     asset_server: &Res<AssetServer>,
+    respawn: bool,
+    flip: BVec2,
 ) {
     commands.entity(map_entity).insert((
         Name::new(format!(
@@ -58,49 +149,76 @@ pub(super) fn load_map(
         TiledMapMarker,
     ));
 
-    let map_type = get_map_type(&tiled_map.map);
-    let map_size = get_map_size(&tiled_map.map);
-    let grid_size = get_grid_size(&tiled_map.map);
+    // Tiles drawing order inside a layer is handled by bevy_ecs_tilemap, which matches
+    // Tiled default 'right-down' order (see `TilemapRenderSettings::y_sort` for isometric maps)
+    if tiled_map.map.render_order != tiled::RenderOrder::RightDown {
+        warn!(
+            "Map '{:?}' uses '{:?}' render order which is not supported: tiles will be rendered using 'RightDown' order",
+            map_handle.path(),
+            tiled_map.map.render_order
+        );
+    }
 
-    let mut layer_events: Vec<TiledLayerCreated> = Vec::new();
-    let mut object_events: Vec<TiledObjectCreated> = Vec::new();
-    let mut special_tile_events: Vec<TiledSpecialTileCreated> = Vec::new();
+    let requested_flip = flip;
+    let flip = map_flip(&tiled_map.map, flip);
+    if flip != requested_flip {
+        warn!(
+            "Map '{:?}' is infinite or not orthogonal and cannot be mirrored",
+            map_handle.path()
+        );
+    }
 
-    // Order of the differents layers in the .TMX file is important:
-    // a layer appearing last in the .TMX should appear "on top" of previous layers
-    // Start with a negative offset so the upper layer will be at Z-offset = 0
-    let mut offset_z = tiled_map.map.layers().len() as f32 * (-100.0);
+    // Reserve events capacity from top-level layers and their objects, which are cheap to compute
+    // (special tiles count is only known while spawning tiles)
+    let layers_count = tiled_map.map.layers().len();
+    let objects_count = tiled_map
+        .map
+        .layers()
+        .filter_map(|layer| layer.as_object_layer())
+        .map(|object_layer| object_layer.objects().len())
+        .sum();
+    let mut layer_events: Vec<TiledLayerCreated> = Vec::with_capacity(layers_count);
+    let mut object_events: Vec<TiledObjectCreated> = Vec::with_capacity(objects_count);
+    let mut special_tile_events: Vec<TiledSpecialTileCreated> = Vec::new();
 
     // Once materials have been created/added we need to then create the layers.
     for (layer_id, layer) in tiled_map.map.layers().enumerate() {
         // Spawn layer entity and attach it to the map entity
         let layer_entity = commands
-            .spawn(TiledMapLayer {
-                map_handle_id: map_handle.id(),
-            })
+            .spawn((
+                TiledMapLayer {
+                    map_handle_id: map_handle.id(),
+                },
+                TiledMapReference(map_entity),
+                TiledLayerId(layer.id()),
+                TiledLayerAuthoredColor {
+                    tint: layer.tint_color.map_or(Color::WHITE, |c| {
+                        Color::srgba_u8(c.red, c.green, c.blue, c.alpha)
+                    }),
+                    opacity: layer.opacity,
+                },
+            ))
             .set_parent(map_entity)
             .id();
 
-        // Increment Z offset
-        offset_z += tiled_settings.layer_z_offset;
-
-        // Apply layer offset and MapPositioning setting
-        let offset_transform = Transform::from_xyz(layer.offset_x, -layer.offset_y, offset_z);
-        commands
-            .entity(layer_entity)
-            .insert(match &tiled_settings.layer_positioning {
-                LayerPositioning::TiledOffset => offset_transform,
-                LayerPositioning::Centered => {
-                    get_tilemap_center_transform(&map_size, &grid_size, &map_type, 0.)
-                        * offset_transform
-                }
+        let transform = layer_transform(tiled_map, layer_id, &layer, tiled_settings, config, flip);
+        commands.entity(layer_entity).insert(transform);
+        if layer.parallax_x != 1. || layer.parallax_y != 1. {
+            commands.entity(layer_entity).insert(TiledLayerParallax {
+                parallax_x: layer.parallax_x,
+                parallax_y: layer.parallax_y,
+                base_position: transform.translation.truncate(),
+                origin: parallax_origin(tiled_map, tiled_settings, flip),
             });
+        }
 
         let layer_infos = TiledLayerCreated {
             map: map_entity,
             layer: layer_entity,
             map_handle: map_handle.clone(),
             layer_id,
+            name: layer.name.clone(),
+            respawn,
         };
 
         match layer.layer_type() {
@@ -118,6 +236,7 @@ pub(super) fn load_map(
                     render_settings,
                     &mut tiled_id_storage.tiles,
                     &mut special_tile_events,
+                    flip,
                 );
             }
             LayerType::Objects(object_layer) => {
@@ -130,8 +249,10 @@ pub(super) fn load_map(
                     tiled_map,
                     &layer_infos,
                     object_layer,
+                    config,
                     &mut tiled_id_storage.objects,
                     &mut object_events,
+                    flip,
                 );
             }
             LayerType::Group(_group_layer) => {
@@ -146,7 +267,14 @@ pub(super) fn load_map(
                     Name::new(format!("TiledMapImageLayer({})", layer.name)),
                     TiledMapImageLayer,
                 ));
-                load_image_layer(commands, tiled_map, &layer_infos, image_layer, asset_server);
+                load_image_layer(
+                    commands,
+                    tiled_map,
+                    &layer_infos,
+                    image_layer,
+                    asset_server,
+                    flip,
+                );
             }
         };
 
@@ -161,34 +289,68 @@ pub(super) fn load_map(
             .clone()
             .hydrate(&tiled_id_storage.objects);
 
+        let pre_insert = |commands: &mut Commands, entity: Entity, target| {
+            commands.trigger(TiledPropertiesPreInsert {
+                map: map_entity,
+                entity,
+                target,
+            });
+        };
+
+        pre_insert(commands, map_entity, TiledPropertiesTarget::Map);
         commands.entity(map_entity).insert_properties(props.map);
 
         for (id, &entity) in tiled_id_storage.objects.iter() {
-            commands
-                .entity(entity)
-                .insert_properties(props.objects.remove(id).unwrap());
+            let p = props.objects.remove(id).unwrap();
+            for type_id in p.type_ids() {
+                tiled_id_storage
+                    .classes
+                    .entry(type_id)
+                    .or_default()
+                    .push(entity);
+            }
+            pre_insert(commands, entity, TiledPropertiesTarget::Object(*id));
+            commands.entity(entity).insert_properties(p);
         }
 
         for (id, &entity) in tiled_id_storage.layers.iter() {
+            pre_insert(commands, entity, TiledPropertiesTarget::Layer(*id));
             commands
                 .entity(entity)
                 .insert_properties(props.layers.remove(id).unwrap());
         }
 
         for (id, entities) in tiled_id_storage.tiles.iter() {
+            for &entity in entities {
+                pre_insert(
+                    commands,
+                    entity,
+                    TiledPropertiesTarget::Tile(id.0.clone(), id.1),
+                );
+            }
             let Some(p) = props.tiles.get(&id.0).and_then(|e| e.get(&id.1)) else {
                 continue;
             };
+            for type_id in p.type_ids() {
+                tiled_id_storage
+                    .classes
+                    .entry(type_id)
+                    .or_default()
+                    .extend(entities);
+            }
             for &entity in entities {
                 commands.entity(entity).insert_properties(p.clone());
             }
         }
+
+        commands.trigger(TiledPropertiesHydrated { map: map_entity });
     }
 
     // Send events
     commands.trigger(TiledMapCreated {
         map: map_entity,
         map_handle: map_handle.clone(),
+        respawn,
     });
     for e in layer_events {
         commands.trigger(e);
@@ -211,15 +373,25 @@ fn load_tiles_layer(
     _render_settings: &TilemapRenderSettings,
     entity_map: &mut HashMap<(String, TileId), Vec<Entity>>,
     event_list: &mut Vec<TiledSpecialTileCreated>,
+    flip: BVec2,
 ) {
     // The TilemapBundle requires that all tile images come exclusively from a single
     // tiled texture or from a Vec of independent per-tile images. Furthermore, all of
     // the per-tile images must be the same size. Since Tiled allows tiles of mixed
     // tilesets on each layer and allows differently-sized tile images in each tileset,
     // this means we need to load each combination of tileset and layer separately.
+    if tiled_map.map.tilesets().is_empty() {
+        log::trace!("Map has no tileset, skip tiles layer '{}'", layer.name);
+        return;
+    }
     for (tileset_index, tileset) in tiled_map.map.tilesets().iter().enumerate() {
+        // Tilesets without texture were already reported when loading the asset
         let Some(tilemap_texture) = tiled_map.tilemap_textures.get(&tileset_index) else {
-            log::warn!("Skipped creating layer with missing tilemap textures.");
+            log::trace!(
+                "Skipped creating layer '{}' for tileset '{}' with missing tilemap textures.",
+                layer.name,
+                tileset.name
+            );
             continue;
         };
 
@@ -235,6 +407,7 @@ fn load_tiles_layer(
                     layer.name, tileset.name
                 )),
                 TiledMapTileLayerForTileset,
+                TiledMapReference(layer_infos.map),
             ))
             .set_parent(layer_infos.layer)
             .id();
@@ -250,6 +423,7 @@ fn load_tiles_layer(
                 tilemap_texture,
                 entity_map,
                 event_list,
+                flip,
             ),
             tiled::TileLayer::Infinite(layer_data) => {
                 let (storage, new_map_size, origin) = load_infinite_tiles_layer(
@@ -310,8 +484,10 @@ fn load_finite_tiles_layer(
     tilemap_texture: &TilemapTexture,
     entity_map: &mut HashMap<(String, TileId), Vec<Entity>>,
     event_list: &mut Vec<TiledSpecialTileCreated>,
+    flip: BVec2,
 ) -> TileStorage {
     let map_size = get_map_size(&tiled_map.map);
+    let map_type = get_map_type(&tiled_map.map);
     let mut tile_storage = TileStorage::empty(map_size);
     for x in 0..map_size.x {
         for y in 0..map_size.y {
@@ -344,25 +520,31 @@ fn load_finite_tiles_layer(
                 _ => unreachable!()
             };
 
-            let tile_pos = TilePos { x, y };
+            // Mirror tiles along the map center axes
+            let tile_pos = TilePos {
+                x: if flip.x { map_size.x - 1 - x } else { x },
+                y: if flip.y { map_size.y - 1 - y } else { y },
+            };
             let tile_entity = commands
                 .spawn((
                     TileBundle {
                         position: tile_pos,
                         tilemap_id: TilemapId(layer_for_tileset_entity),
                         texture_index: TileTextureIndex(texture_index),
-                        flip: TileFlip {
-                            x: layer_tile_data.flip_h,
-                            y: layer_tile_data.flip_v,
-                            d: layer_tile_data.flip_d,
-                        },
+                        flip: tile_flip(layer_tile_data, &map_type, flip),
                         ..Default::default()
                     },
                     Name::new(format!("TiledMapTile({},{})", tile_pos.x, tile_pos.y)),
                     TiledMapTile,
+                    TiledTileSource {
+                        tileset_index: tileset_index as u32,
+                        tile_id: layer_tile.id(),
+                    },
+                    TiledMapReference(layer_infos.map),
                 ))
                 .set_parent(layer_for_tileset_entity)
                 .id();
+            insert_tile_probability(commands, tile_entity, &tile);
 
             handle_special_tile(
                 commands,
@@ -397,18 +579,11 @@ fn load_infinite_tiles_layer(
     entity_map: &mut HashMap<(String, TileId), Vec<Entity>>,
     event_list: &mut Vec<TiledSpecialTileCreated>,
 ) -> (TileStorage, TilemapSize, (f32, f32)) {
+    let map_type = get_map_type(&_tiled_map.map);
+
     // Determine top left coordinate so we can offset the map.
-    let (topleft_x, topleft_y) = infinite_layer
-        .chunks()
-        .fold((999999, 999999), |acc, (pos, _)| {
-            (acc.0.min(pos.0), acc.1.min(pos.1))
-        });
-
-    let (bottomright_x, bottomright_y) = infinite_layer
-        .chunks()
-        .fold((topleft_x, topleft_y), |acc, (pos, _)| {
-            (acc.0.max(pos.0), acc.1.max(pos.1))
-        });
+    let ((topleft_x, topleft_y), (bottomright_x, bottomright_y)) =
+        infinite_layer_bounds(infinite_layer);
 
     log::info!(
         "(infinite map) topleft: ({}, {}), bottomright: ({}, {})",
@@ -480,18 +655,20 @@ fn load_infinite_tiles_layer(
                             position: tile_pos,
                             tilemap_id: TilemapId(layer_for_tileset_entity),
                             texture_index: TileTextureIndex(texture_index),
-                            flip: TileFlip {
-                                x: layer_tile_data.flip_h,
-                                y: layer_tile_data.flip_v,
-                                d: layer_tile_data.flip_d,
-                            },
+                            flip: tile_flip(layer_tile_data, &map_type, BVec2::FALSE),
                             ..Default::default()
                         },
                         Name::new(format!("Tile({},{})", tile_pos.x, tile_pos.y)),
                         TiledMapTile,
+                        TiledTileSource {
+                            tileset_index: tileset_index as u32,
+                            tile_id: layer_tile.id(),
+                        },
+                        TiledMapReference(layer_infos.map),
                     ))
                     .set_parent(layer_for_tileset_entity)
                     .id();
+                insert_tile_probability(commands, tile_entity, &tile);
                 handle_special_tile(
                     commands,
                     TiledSpecialTileCreated::from_layer(
@@ -522,8 +699,10 @@ fn load_objects_layer(
     tiled_map: &TiledMap,
     layer_infos: &TiledLayerCreated,
     object_layer: ObjectLayer,
+    config: &TiledMapPluginConfig,
     entity_map: &mut HashMap<u32, Entity>,
     event_list: &mut Vec<TiledObjectCreated>,
+    flip: BVec2,
 ) {
     let map_type = get_map_type(&tiled_map.map);
     let grid_size = get_grid_size(&tiled_map.map);
@@ -531,49 +710,204 @@ fn load_objects_layer(
 
     for (object_id, object_data) in object_layer.objects().enumerate() {
         let object_position = from_tiled_coords_to_bevy(
-            Vec2::new(object_data.x, object_data.y),
+            flip_tiled_position(
+                &tiled_map.map,
+                Vec2::new(object_data.x, object_data.y),
+                flip,
+            ),
             &map_type,
             &map_size,
             &grid_size,
         );
         let object_entity = commands
             .spawn((
-                Name::new(format!("Object({})", object_data.name)),
+                object_name(&object_data, config.object_name_property.as_deref()),
                 TiledMapObject,
-                Transform::from_xyz(object_position.x, object_position.y, 0.),
+                TiledObjectId(object_data.id()),
+                TiledMapReference(layer_infos.map),
+                object_transform(object_position, object_data.rotation, flip).with_translation(
+                    object_position
+                        .extend(object_z(&object_data, config.object_z_property.as_deref())),
+                ),
             ))
             .set_parent(layer_infos.layer)
             .id();
 
+        if let tiled::ObjectShape::Point(_, _) = &object_data.shape {
+            commands.entity(object_entity).insert(TiledObjectPoint);
+        }
+
+        #[cfg(feature = "text")]
+        if let tiled::ObjectShape::Text { .. } = &object_data.shape {
+            spawn_object_text(commands, object_entity, &object_data.shape);
+        }
+
         entity_map.insert(object_data.id(), object_entity);
         event_list.push(TiledObjectCreated::from_layer(
             layer_infos,
             object_entity,
             object_id,
+            &object_data,
         ));
     }
 }
 
+#[cfg(feature = "text")]
+fn spawn_object_text(commands: &mut Commands, object_entity: Entity, shape: &tiled::ObjectShape) {
+    use bevy::{sprite::Anchor, text::TextBounds};
+    use tiled::{HorizontalAlignment, VerticalAlignment};
+
+    let tiled::ObjectShape::Text {
+        pixel_size,
+        wrap,
+        color,
+        halign,
+        valign,
+        text,
+        width,
+        height,
+        ..
+    } = shape
+    else {
+        return;
+    };
+
+    // Object position is the top-left corner of the text box:
+    // place the text anchor according to Tiled alignment
+    let (justify, x) = match halign {
+        HorizontalAlignment::Left => (JustifyText::Left, 0.),
+        HorizontalAlignment::Center => (JustifyText::Center, width / 2.),
+        HorizontalAlignment::Right => (JustifyText::Right, *width),
+        HorizontalAlignment::Justify => (JustifyText::Justified, 0.),
+    };
+    let y = match valign {
+        VerticalAlignment::Top => 0.,
+        VerticalAlignment::Center => -height / 2.,
+        VerticalAlignment::Bottom => -height,
+    };
+    let anchor = match (halign, valign) {
+        (HorizontalAlignment::Center, VerticalAlignment::Top) => Anchor::TopCenter,
+        (HorizontalAlignment::Center, VerticalAlignment::Center) => Anchor::Center,
+        (HorizontalAlignment::Center, VerticalAlignment::Bottom) => Anchor::BottomCenter,
+        (HorizontalAlignment::Right, VerticalAlignment::Top) => Anchor::TopRight,
+        (HorizontalAlignment::Right, VerticalAlignment::Center) => Anchor::CenterRight,
+        (HorizontalAlignment::Right, VerticalAlignment::Bottom) => Anchor::BottomRight,
+        (_, VerticalAlignment::Top) => Anchor::TopLeft,
+        (_, VerticalAlignment::Center) => Anchor::CenterLeft,
+        (_, VerticalAlignment::Bottom) => Anchor::BottomLeft,
+    };
+
+    // Tiled font family is not resolved: always use Bevy default font
+    commands
+        .spawn((
+            Name::new("TiledText"),
+            Text2d::new(text.clone()),
+            TextFont {
+                font_size: *pixel_size as f32,
+                ..default()
+            },
+            TextColor(Color::srgba_u8(
+                color.red,
+                color.green,
+                color.blue,
+                color.alpha,
+            )),
+            TextLayout::new(
+                justify,
+                if *wrap {
+                    LineBreak::WordBoundary
+                } else {
+                    LineBreak::NoWrap
+                },
+            ),
+            TextBounds {
+                width: wrap.then_some(*width),
+                height: None,
+            },
+            anchor,
+            Transform::from_xyz(x, y, 0.),
+        ))
+        .set_parent(object_entity);
+}
+
+/// Compute an object [Transform], relative to its layer.
+///
+/// Tiled rotates objects clockwise, in degrees, around their position: top-left corner
+/// for most shapes, which is where the object [Entity] origin is.
+/// Mirrored objects get a negative scale along the flipped axes, so their shape
+/// (and colliders or sprites attached to them) are mirrored as well.
+fn object_transform(object_position: Vec2, rotation: f32, flip: BVec2) -> Transform {
+    // Mirroring along a single axis reverses the rotation direction
+    let rotation = if flip.x != flip.y {
+        rotation
+    } else {
+        -rotation
+    };
+    Transform::from_xyz(object_position.x, object_position.y, 0.)
+        .with_rotation(Quat::from_rotation_z(rotation.to_radians()))
+        .with_scale(Vec3::select(
+            BVec3::new(flip.x, flip.y, false),
+            Vec3::NEG_ONE,
+            Vec3::ONE,
+        ))
+}
+
+fn object_z(object_data: &tiled::ObjectData, z_property: Option<&str>) -> f32 {
+    match z_property.and_then(|property| object_data.properties.get(property)) {
+        Some(tiled::PropertyValue::FloatValue(z)) => *z,
+        Some(tiled::PropertyValue::IntValue(z)) => *z as f32,
+        _ => 0.,
+    }
+}
+
+fn object_name(object_data: &tiled::ObjectData, name_property: Option<&str>) -> Name {
+    match name_property.and_then(|property| object_data.properties.get(property)) {
+        Some(tiled::PropertyValue::StringValue(name)) => Name::new(name.clone()),
+        _ => Name::new(format!("Object({})", object_data.name)),
+    }
+}
+
 fn load_image_layer(
     commands: &mut Commands,
     tiled_map: &TiledMap,
     layer_infos: &TiledLayerCreated,
     image_layer: ImageLayer,
     asset_server: &Res<AssetServer>,
+    flip: BVec2,
 ) {
     let map_type = get_map_type(&tiled_map.map);
     let grid_size = get_grid_size(&tiled_map.map);
     let map_size = get_map_size(&tiled_map.map);
 
     if let Some(image) = &image_layer.image {
-        let image_position =
-            from_tiled_coords_to_bevy(Vec2::splat(0.), &map_type, &map_size, &grid_size);
+        // When mirrored, the image top-left corner is its opposite corner
+        let image_size = Vec2::new(image.width as f32, image.height as f32);
+        let image_position = from_tiled_coords_to_bevy(
+            flip_tiled_position(
+                &tiled_map.map,
+                Vec2::select(flip, image_size, Vec2::ZERO),
+                flip,
+            ),
+            &map_type,
+            &map_size,
+            &grid_size,
+        );
         commands
             .spawn((
                 Name::new(format!("Image({})", image.source.display())),
                 TiledMapImage,
+                TiledMapReference(layer_infos.map),
                 Sprite {
-                    image: asset_server.load(image.source.clone()),
+                    image: asset_server.load(tiled_asset_path(
+                        &image.source,
+                        layer_infos
+                            .map_handle
+                            .path()
+                            .map(|path| path.source())
+                            .unwrap_or(&AssetSourceId::Default),
+                    )),
+                    flip_x: flip.x,
+                    flip_y: flip.y,
                     ..Default::default()
                 },
                 Transform::from_xyz(
@@ -586,6 +920,30 @@ fn load_image_layer(
     }
 }
 
+fn tile_flip(
+    layer_tile_data: &tiled::LayerTileData,
+    map_type: &TilemapType,
+    map_flip: BVec2,
+) -> TileFlip {
+    // Tiled applies the diagonal flip first, so mirroring the map only toggles horizontal and vertical flips
+    TileFlip {
+        x: layer_tile_data.flip_h != map_flip.x,
+        y: layer_tile_data.flip_v != map_flip.y,
+        // For hexagonal maps, Tiled uses the diagonal flag for 60° rotations
+        // which are not supported by bevy_ecs_tilemap: ignore it
+        d: layer_tile_data.flip_d && !matches!(map_type, TilemapType::Hexagon(_)),
+    }
+}
+
+fn insert_tile_probability(commands: &mut Commands, tile_entity: Entity, tile: &Tile) {
+    // Only insert the component when the probability is not the default one
+    if tile.probability != 1. {
+        commands
+            .entity(tile_entity)
+            .insert(TiledTileProbability(tile.probability));
+    }
+}
+
 fn get_animated_tile(tile: &Tile) -> Option<AnimatedTile> {
     let Some(animation_data) = &tile.animation else {
         return None;
@@ -652,3 +1010,146 @@ fn handle_special_tile(
         event_list.push(tile_infos);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotated_rectangle_corners() {
+        let (width, height) = (40., 20.);
+        let shape = tiled::ObjectShape::Rect { width, height };
+        let position = Vec2::new(100., 50.);
+        let transform = object_transform(position, 45., BVec2::FALSE);
+
+        // Corners computed the same way Tiled does: rotate in Tiled referential (y-axis pointing down)
+        // then flip the y-axis
+        let rotation = Mat2::from_angle(45_f32.to_radians());
+        for vertex in object_shape_vertices(&shape) {
+            let expected = rotation * vertex;
+            let expected = position + Vec2::new(expected.x, -expected.y);
+            let actual = transform
+                .transform_point(Vec3::new(vertex.x, -vertex.y, 0.))
+                .truncate();
+            assert!(
+                actual.abs_diff_eq(expected, 1e-4),
+                "{actual:?} != {expected:?}"
+            );
+        }
+
+        // Top-right corner of a 45° rotated rectangle
+        let top_right = transform
+            .transform_point(Vec3::new(width, 0., 0.))
+            .truncate();
+        let half_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(top_right.abs_diff_eq(
+            position + Vec2::new(width * half_sqrt2, -width * half_sqrt2),
+            1e-4
+        ));
+    }
+
+    fn load_test_map(path: &str) -> tiled::Map {
+        tiled::Loader::new()
+            .load_tmx_map(std::path::Path::new("assets").join(path))
+            .unwrap()
+    }
+
+    /// Center of a tile, as spawned by [load_finite_tiles_layer].
+    fn tile_center(map: &tiled::Map, tile_pos: TilePos) -> Vec2 {
+        let grid_size = get_grid_size(map);
+        tile_pos.center_in_world(&grid_size, &get_map_type(map))
+            + Vec2::new(grid_size.x / 2., grid_size.y / 2.)
+    }
+
+    /// Position of an object located at the center of a tile, as spawned by [load_objects_layer].
+    fn object_at_tile_center(map: &tiled::Map, tiled_index: UVec2, flip: BVec2) -> Vec2 {
+        let grid_size = get_grid_size(map);
+        let tiled_position =
+            (tiled_index.as_vec2() + Vec2::splat(0.5)) * Vec2::new(grid_size.x, grid_size.y);
+        from_tiled_coords_to_bevy(
+            flip_tiled_position(map, tiled_position, flip),
+            &get_map_type(map),
+            &get_map_size(map),
+            &grid_size,
+        )
+    }
+
+    #[test]
+    fn mirrored_tiles_and_objects_match() {
+        let map = load_test_map("finite.tmx");
+        let map_size = get_map_size(&map);
+        for flip in [
+            BVec2::new(true, false),
+            BVec2::new(false, true),
+            BVec2::TRUE,
+        ] {
+            assert_eq!(map_flip(&map, flip), flip);
+            for (x, y) in [(0, 0), (3, 1), (map_size.x - 1, map_size.y - 1)] {
+                // Same tile position computation as load_finite_tiles_layer()
+                let mirrored_tile = TilePos {
+                    x: if flip.x { map_size.x - 1 - x } else { x },
+                    y: if flip.y { map_size.y - 1 - y } else { y },
+                };
+                let tiled_index = UVec2::new(x, map_size.y - 1 - y);
+                let object = object_at_tile_center(&map, tiled_index, flip);
+                let center = tile_center(&map, mirrored_tile);
+                assert!(
+                    object.abs_diff_eq(center, 1e-4),
+                    "{flip:?}: {object:?} != {center:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn non_orthogonal_maps_are_not_mirrored() {
+        for path in [
+            "isometric_diamond_map.tmx",
+            "isometric_staggered_map.tmx",
+            "hex_map_pointy_top_odd.tmx",
+            "hex_map_flat_top_even.tmx",
+            "infinite.tmx",
+        ] {
+            let map = load_test_map(path);
+            assert_eq!(map_flip(&map, BVec2::TRUE), BVec2::FALSE, "{path}");
+
+            // Objects keep their original position
+            if !map.infinite() {
+                let tiled_index = UVec2::new(1, map.height - 2);
+                assert_eq!(
+                    object_at_tile_center(&map, tiled_index, BVec2::FALSE),
+                    object_at_tile_center(&map, tiled_index, map_flip(&map, BVec2::TRUE))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mirrored_rectangle_corners() {
+        let shape = tiled::ObjectShape::Rect {
+            width: 40.,
+            height: 20.,
+        };
+        let position = Vec2::new(100., 50.);
+        let transform = object_transform(position, 30., BVec2::FALSE);
+
+        // Mirror around the X = 0 and Y = 0 axes
+        for flip in [
+            BVec2::new(true, false),
+            BVec2::new(false, true),
+            BVec2::TRUE,
+        ] {
+            let mirror = Vec2::select(flip, Vec2::NEG_ONE, Vec2::ONE);
+            let mirrored = object_transform(position * mirror, 30., flip);
+            for vertex in object_shape_vertices(&shape) {
+                let local = Vec3::new(vertex.x, -vertex.y, 0.);
+                let expected = transform.transform_point(local).truncate() * mirror;
+                let actual = mirrored.transform_point(local).truncate();
+                assert!(
+                    actual.abs_diff_eq(expected, 1e-4),
+                    "{flip:?}: {actual:?} != {expected:?}"
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //! This module contains utilities to work with Tiled names.
 use bevy::utils::HashSet;
+use std::sync::Arc;
+
+/// Function used to match names with [ObjectNames::Predicate].
+pub type ObjectNamePredicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
 
 /// A struct to specify names when using [ObjectNameFilter]
 ///
@@ -16,7 +20,7 @@ use bevy::utils::HashSet;
 /// assert_eq!(ObjectNameFilter::from(&ObjectNames::All).contains(&name_to_check), true);
 /// assert_eq!(ObjectNameFilter::from(&ObjectNames::None).contains(&name_to_check), false);
 /// ```
-#[derive(Default, Clone, PartialEq)]
+#[derive(Default, Clone)]
 pub enum ObjectNames {
     /// Matches all names.
     #[default]
@@ -25,10 +29,88 @@ pub enum ObjectNames {
     ///
     /// Names are case-insensitive and leading/trailing whitespace will be trimmed.
     Names(Vec<String>),
+    /// Matches all names except provided names.
+    ///
+    /// Names are case-insensitive and leading/trailing whitespace will be trimmed.
+    Exclude(Vec<String>),
+    /// Matches names which match `include` but are not part of `exclude` names.
+    ///
+    /// Names are case-insensitive and leading/trailing whitespace will be trimmed.
+    IncludeExclude {
+        /// Names to match.
+        include: Box<ObjectNames>,
+        /// Names to exclude from the match.
+        exclude: Vec<String>,
+    },
+    /// Matches names for which provided function returns `true`.
+    ///
+    /// Names are provided as-is, without any transformation.
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// let names = ObjectNames::Predicate(Arc::new(|name| {
+    ///     name.starts_with("wall_") && name.len() < 10
+    /// }));
+    ///
+    /// assert_eq!(ObjectNameFilter::from(&names).contains("wall_1"), true);
+    /// assert_eq!(ObjectNameFilter::from(&names).contains("door_1"), false);
+    /// ```
+    Predicate(ObjectNamePredicate),
     /// Does not match any name.
     None,
 }
 
+impl PartialEq for ObjectNames {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ObjectNames::All, ObjectNames::All) => true,
+            (ObjectNames::Names(a), ObjectNames::Names(b)) => a == b,
+            (ObjectNames::Exclude(a), ObjectNames::Exclude(b)) => a == b,
+            (
+                ObjectNames::IncludeExclude {
+                    include: a,
+                    exclude: ea,
+                },
+                ObjectNames::IncludeExclude {
+                    include: b,
+                    exclude: eb,
+                },
+            ) => a == b && ea == eb,
+            // Functions cannot be compared: only check if they are the same instance
+            (ObjectNames::Predicate(a), ObjectNames::Predicate(b)) => Arc::ptr_eq(a, b),
+            (ObjectNames::None, ObjectNames::None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl ObjectNames {
+    /// Exclude provided names from this [ObjectNames].
+    ///
+    /// Example:
+    /// ```rust,no_run
+    /// use bevy_ecs_tiled::prelude::*;
+    ///
+    /// let names = ObjectNames::Names(vec!["a".to_string(), "b".to_string()])
+    ///     .except(vec!["b".to_string()]);
+    ///
+    /// assert_eq!(ObjectNameFilter::from(&names).contains("a"), true);
+    /// assert_eq!(ObjectNameFilter::from(&names).contains("b"), false);
+    /// ```
+    pub fn except(self, exclude: Vec<String>) -> Self {
+        match self {
+            ObjectNames::All => ObjectNames::Exclude(exclude),
+            other => ObjectNames::IncludeExclude {
+                include: Box::new(other),
+                exclude,
+            },
+        }
+    }
+}
+
 /// Allow to check if a provided [ObjectNames] matches a given name.
 ///
 /// Example:
@@ -43,6 +125,8 @@ pub enum ObjectNames {
 /// assert_eq!(ObjectNameFilter::from(&non_matching_allowed_names).contains(&name_to_check), false);
 /// assert_eq!(ObjectNameFilter::from(&ObjectNames::All).contains(&name_to_check), true);
 /// assert_eq!(ObjectNameFilter::from(&ObjectNames::None).contains(&name_to_check), false);
+/// assert_eq!(ObjectNameFilter::from(&ObjectNames::Exclude(vec!("some name".to_string()))).contains(&name_to_check), false);
+/// assert_eq!(ObjectNameFilter::from(&matching_allowed_names).contains("Some Name"), true);
 /// ```
 #[derive(Clone)]
 pub enum ObjectNameFilter {
@@ -50,23 +134,40 @@ pub enum ObjectNameFilter {
     All,
     /// Matches only provided names.
     Names(HashSet<String>),
+    /// Matches all names except provided names.
+    Exclude(HashSet<String>),
+    /// Matches names which match the inner filter but are not part of provided names.
+    IncludeExclude(Box<ObjectNameFilter>, HashSet<String>),
+    /// Matches names for which provided function returns `true`.
+    Predicate(ObjectNamePredicate),
     /// Does not match any name.
     None,
 }
 
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn normalize_names(names: &[String]) -> HashSet<String> {
+    names
+        .iter()
+        .map(|x| normalize_name(x))
+        .filter(|x| !x.is_empty())
+        .collect()
+}
+
 impl From<&ObjectNames> for ObjectNameFilter {
     /// Initialize a [ObjectNameFilter] from an [ObjectNames].
     fn from(value: &ObjectNames) -> Self {
         match value {
             ObjectNames::All => ObjectNameFilter::All,
-            ObjectNames::Names(names) => {
-                let names = names
-                    .iter()
-                    .map(|x| x.trim().to_lowercase())
-                    .filter(|x| !x.is_empty())
-                    .collect();
-                ObjectNameFilter::Names(names)
-            }
+            ObjectNames::Names(names) => ObjectNameFilter::Names(normalize_names(names)),
+            ObjectNames::Exclude(names) => ObjectNameFilter::Exclude(normalize_names(names)),
+            ObjectNames::IncludeExclude { include, exclude } => ObjectNameFilter::IncludeExclude(
+                Box::new(ObjectNameFilter::from(include.as_ref())),
+                normalize_names(exclude),
+            ),
+            ObjectNames::Predicate(predicate) => ObjectNameFilter::Predicate(predicate.clone()),
             ObjectNames::None => ObjectNameFilter::None,
         }
     }
@@ -74,10 +175,17 @@ impl From<&ObjectNames> for ObjectNameFilter {
 
 impl ObjectNameFilter {
     /// Determine if provided [str] matches the filter.
+    ///
+    /// Comparison with provided names is case-insensitive and ignores leading/trailing whitespace.
     pub fn contains(&self, name: &str) -> bool {
         match self {
             ObjectNameFilter::All => true,
-            ObjectNameFilter::Names(names) => names.contains(name),
+            ObjectNameFilter::Names(names) => names.contains(&normalize_name(name)),
+            ObjectNameFilter::Exclude(names) => !names.contains(&normalize_name(name)),
+            ObjectNameFilter::IncludeExclude(include, exclude) => {
+                include.contains(name) && !exclude.contains(&normalize_name(name))
+            }
+            ObjectNameFilter::Predicate(predicate) => predicate(name),
             ObjectNameFilter::None => false,
         }
     }
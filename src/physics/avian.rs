@@ -57,9 +57,44 @@ impl TiledPhysicsBackend for TiledPhysicsAvianBackend {
             name: format!("Avian[{}]", object_data.name),
             entity: commands.spawn(collider).id(),
             position: pos,
-            rotation: -object_data.rotation,
+            rotation: match collider_source.ty {
+                // Objects entities are already rotated
+                TiledColliderSourceType::Object { .. } => 0.,
+                TiledColliderSourceType::Tile { .. } => -object_data.rotation,
+            },
         })
     }
+
+    fn insert_rigid_body(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        rigid_body: TiledRigidBody,
+    ) {
+        commands.entity(entity).insert(match rigid_body {
+            TiledRigidBody::Static => RigidBody::Static,
+            TiledRigidBody::Dynamic => RigidBody::Dynamic,
+            TiledRigidBody::Kinematic => RigidBody::Kinematic,
+        });
+    }
+
+    fn insert_collider_material(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        material: TiledColliderMaterial,
+    ) {
+        let mut entity = commands.entity(entity);
+        if let Some(friction) = material.friction {
+            entity.insert(Friction::new(friction));
+        }
+        if let Some(restitution) = material.restitution {
+            entity.insert(Restitution::new(restitution));
+        }
+        if material.sensor {
+            entity.insert(Sensor);
+        }
+    }
 }
 
 fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vector, Collider)> {
@@ -71,13 +106,20 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vector, Collider)>
             Some((pos, shape))
         }
         ObjectShape::Ellipse { width, height } => {
-            let shape = Collider::ellipse(width / 2., height / 2.);
+            let shape = if width == height {
+                Collider::circle(width / 2.)
+            } else {
+                Collider::ellipse(width / 2., height / 2.)
+            };
             let pos = Vector::new(width / 2., -height / 2.);
             Some((pos, shape))
         }
-        ObjectShape::Polyline { points } => {
+        ObjectShape::Polyline { .. } => {
             let shape = Collider::polyline(
-                points.iter().map(|(x, y)| Vector::new(*x, -*y)).collect(),
+                object_shape_vertices(shape)
+                    .into_iter()
+                    .map(|p| Vector::new(p.x, -p.y))
+                    .collect(),
                 None,
             );
             Some((Vector::ZERO, shape))
@@ -87,9 +129,9 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vector, Collider)>
                 return None;
             }
 
-            let points = points
-                .iter()
-                .map(|(x, y)| Vector::new(*x, -*y))
+            let points = object_shape_vertices(shape)
+                .into_iter()
+                .map(|p| Vector::new(p.x, -p.y))
                 .collect::<Vec<_>>();
 
             let indices = (0..points.len() as u32 - 1)
@@ -104,3 +146,28 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vector, Collider)>
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polyline_is_not_closed() {
+        let points = vec![(0., 0.), (10., 0.), (10., 10.)];
+
+        let (_, polyline) = get_position_and_collider(&ObjectShape::Polyline {
+            points: points.clone(),
+        })
+        .unwrap();
+        let (_, polygon) = get_position_and_collider(&ObjectShape::Polygon { points }).unwrap();
+
+        let polyline = polyline.shape().as_polyline().unwrap();
+        let polygon = polygon.shape().as_polyline().unwrap();
+
+        assert_eq!(polyline.vertices().len(), polygon.vertices().len());
+        // An open chain has one segment less than the matching closed polygon
+        assert_eq!(polyline.indices().len(), 2);
+        assert_eq!(polygon.indices().len(), 3);
+        assert_eq!(polygon.indices()[2], [2, 0]);
+    }
+}
@@ -1,7 +1,7 @@
 //! Module that handles colliders
 use crate::prelude::*;
 use bevy::prelude::*;
-use tiled::{Layer, Map, Object, Tile};
+use tiled::{Layer, Map, Object, ObjectShape, Tile};
 
 /// Marker component for colliders
 #[derive(Component)]
@@ -112,6 +112,27 @@ impl<'a> TiledColliderSource {
         }
     }
 
+    /// Get a copy of the underlying [ObjectShape] of a [TiledColliderSource].
+    pub fn object_shape(&self, map: &'a Map) -> Option<ObjectShape> {
+        match self.ty {
+            TiledColliderSourceType::Tile {
+                layer_id: _,
+                x: _,
+                y: _,
+                object_id,
+            } => self
+                .tile(map)
+                .as_ref()
+                .and_then(|tile| tile.collision.as_ref())
+                .and_then(|collision| collision.object_data().get(object_id))
+                .map(|object_data| object_data.shape.clone()),
+            TiledColliderSourceType::Object {
+                layer_id: _,
+                object_id: _,
+            } => self.object(map).map(|object| object.shape.clone()),
+        }
+    }
+
     // TODO: we should use this function when I figure out how to prevent cloning ObjectData
     // pub fn object_data(&self, map: &'a Map) -> Option<ObjectData> {
     //     match self {
@@ -136,15 +157,27 @@ impl<'a> TiledColliderSource {
 }
 
 /// Spawn informations about a collider
+///
+/// The collider [Entity] is always parented to [TiledColliderSource::entity]:
+/// position and rotation are expressed relative to this [Entity], never in world space.
+/// For an [Object], this [Entity] is already placed at the object position and rotated
+/// like the object, so a backend should only report the offset of the collider shape
+/// from the object origin. For a [Tile], this [Entity] is placed at the tile center
+/// and is not rotated, so a backend should report the collision object rotation.
 #[derive(Clone, Debug)]
 pub struct TiledColliderSpawnInfos {
     /// Name of the collider.
     pub name: String,
     /// [Entity] of the spawned collider.
     pub entity: Entity,
-    /// Relative position of the collider from its parent [Entity].
+    /// Position of the collider, relative to its source [Entity].
+    ///
+    /// This position is rotated by [TiledColliderSpawnInfos::rotation],
+    /// as well as by the source [Entity] rotation.
     pub position: Vec2,
-    /// Relative rotation of the collider from its parent [Entity].
+    /// Rotation of the collider, in degrees, relative to its source [Entity].
+    ///
+    /// Positive values rotate counter-clockwise, whereas Tiled rotates clockwise.
     pub rotation: f32,
 }
 
@@ -157,6 +190,13 @@ pub struct TiledColliderCreated {
     pub collider: TiledColliderSpawnInfos,
     /// Collider source informations.
     pub collider_source: TiledColliderSource,
+    /// Original Tiled [ObjectShape] the collider was built from.
+    ///
+    /// Shape is expressed in Tiled referential, relative to the object origin.
+    /// Can be `None` if a custom backend spawned a collider for an unknown source.
+    pub shape: Option<ObjectShape>,
+    /// Final [Transform] of the collider, relative to its parent [Entity].
+    pub transform: Transform,
 }
 
 impl<'a> TiledColliderCreated {
@@ -181,6 +221,18 @@ impl<'a> TiledColliderCreated {
     }
 }
 
+/// Compute a collider [Transform], relative to its source [Entity].
+///
+/// `offset` is the position of the collision object relative to the source [Entity],
+/// it is not rotated, unlike [TiledColliderSpawnInfos::position].
+pub(super) fn collider_transform(collider: &TiledColliderSpawnInfos, offset: Vec2) -> Transform {
+    Transform {
+        translation: Vec3::new(offset.x, offset.y, 0.),
+        rotation: Quat::from_rotation_z(f32::to_radians(collider.rotation)),
+        ..default()
+    } * Transform::from_translation(Vec3::new(collider.position.x, collider.position.y, 0.))
+}
+
 pub(super) fn spawn_collider<T: super::TiledPhysicsBackend>(
     backend: &T,
     commands: &mut Commands,
@@ -188,18 +240,11 @@ pub(super) fn spawn_collider<T: super::TiledPhysicsBackend>(
     map_handle: &Handle<TiledMap>,
     collider_source: &TiledColliderSource,
     offset: Vec2,
+    material: Option<TiledColliderMaterial>,
 ) {
     if let Some(tiled_map) = map_asset.get(map_handle) {
         if let Some(collider) = backend.spawn_collider(commands, &tiled_map.map, collider_source) {
-            let transform = Transform {
-                translation: Vec3::new(offset.x, offset.y, 0.),
-                rotation: Quat::from_rotation_z(f32::to_radians(collider.rotation)),
-                ..default()
-            } * Transform::from_translation(Vec3::new(
-                collider.position.x,
-                collider.position.y,
-                0.,
-            ));
+            let transform = collider_transform(&collider, offset);
             commands
                 .entity(collider.entity)
                 .insert((
@@ -208,11 +253,91 @@ pub(super) fn spawn_collider<T: super::TiledPhysicsBackend>(
                     Name::new(format!("Collider: {}", collider.name)),
                 ))
                 .set_parent(collider_source.entity);
+            if let Some(material) = material {
+                backend.insert_collider_material(commands, collider.entity, material);
+            }
             commands.trigger(TiledColliderCreated {
                 map_handle: map_handle.clone(),
                 collider,
                 collider_source: *collider_source,
+                shape: collider_source.object_shape(&tiled_map.map),
+                transform,
             });
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn global_transform(world: &mut World, entity: Entity) -> GlobalTransform {
+        *world.entity(entity).get::<GlobalTransform>().unwrap()
+    }
+
+    #[test]
+    fn rotated_object_collider_placement() {
+        let mut app = App::new();
+        app.add_plugins((TransformPlugin, HierarchyPlugin));
+
+        // A 40x20 rectangle at (100, 50), rotated by 30° clockwise in Tiled
+        let (width, height, rotation) = (40., 20., 30_f32);
+        let map_transform = Transform::from_xyz(10., -20., 0.);
+        let object_transform = Transform::from_xyz(100., 50., 0.)
+            .with_rotation(Quat::from_rotation_z(-rotation.to_radians()));
+
+        // Rectangle colliders are centered: backends report the rectangle center
+        // from the object origin, without any rotation since the object is already rotated
+        let collider = TiledColliderSpawnInfos {
+            name: String::from("test"),
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::new(width / 2., -height / 2.),
+            rotation: 0.,
+        };
+
+        let world = app.world_mut();
+        let map = world.spawn(map_transform).id();
+        let object = world.spawn(object_transform).set_parent(map).id();
+        let collider_entity = world
+            .spawn(collider_transform(&collider, Vec2::ZERO))
+            .set_parent(object)
+            .id();
+        app.update();
+
+        // Rectangle center computed the same way Tiled does: rotate in Tiled referential
+        // (y-axis pointing down) then flip the y-axis
+        let center = Mat2::from_angle(rotation.to_radians()) * Vec2::new(width / 2., height / 2.);
+        let expected = map_transform.translation.truncate()
+            + object_transform.translation.truncate()
+            + Vec2::new(center.x, -center.y);
+
+        let actual = global_transform(app.world_mut(), collider_entity);
+        assert!(
+            actual.translation().truncate().abs_diff_eq(expected, 1e-4),
+            "{:?} != {expected:?}",
+            actual.translation()
+        );
+        let (_, _, angle) = actual.rotation().to_euler(EulerRot::XYZ);
+        assert!((angle + rotation.to_radians()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rotated_tile_collider_placement() {
+        // Tile collision objects are placed using an offset from the tile center
+        // and report their own rotation
+        let offset = Vec2::new(-8., 8.);
+        let collider = TiledColliderSpawnInfos {
+            name: String::from("test"),
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::new(4., -2.),
+            rotation: -90.,
+        };
+        let transform = collider_transform(&collider, offset);
+
+        // Collider position is rotated along with the collision object: (4, -2) -> (-2, -4)
+        assert!(transform
+            .translation
+            .truncate()
+            .abs_diff_eq(offset + Vec2::new(-2., -4.), 1e-4));
+    }
+}
@@ -15,7 +15,7 @@ pub mod avian;
 use crate::prelude::*;
 use bevy::prelude::*;
 use prelude::*;
-use tiled::Map;
+use tiled::{Map, Properties, PropertyValue};
 
 /// `bevy_ecs_tiled` physics public exports.
 pub mod prelude {
@@ -24,9 +24,11 @@ pub mod prelude {
     pub use super::collider::*;
     #[cfg(feature = "rapier")]
     pub use super::rapier::*;
+    pub use super::TiledColliderMaterial;
     pub use super::TiledPhysicsBackend;
     pub use super::TiledPhysicsPlugin;
     pub use super::TiledPhysicsSettings;
+    pub use super::TiledRigidBody;
 }
 
 /// Physics backend public trait.
@@ -45,10 +47,130 @@ pub trait TiledPhysicsBackend {
         map: &Map,
         collider_source: &TiledColliderSource,
     ) -> Option<TiledColliderSpawnInfos>;
+
+    /// Function responsible for adding a rigid body to a Tiled object
+    ///
+    /// This function is called for each Tiled object which has a custom property
+    /// named after [TiledPhysicsSettings::rigid_body_property] and should insert
+    /// the backend rigid body on provided [Entity].
+    /// Default implementation does nothing.
+    fn insert_rigid_body(
+        &self,
+        _commands: &mut Commands,
+        _entity: Entity,
+        _rigid_body: TiledRigidBody,
+    ) {
+    }
+
+    /// Function responsible for adding a physics material to a collider
+    ///
+    /// This function is called for each spawned collider whose Tiled object (or tile
+    /// collision object) has at least one of the custom properties described by
+    /// [TiledColliderMaterial] and should insert the backend components on provided [Entity].
+    /// Default implementation does nothing.
+    fn insert_collider_material(
+        &self,
+        _commands: &mut Commands,
+        _entity: Entity,
+        _material: TiledColliderMaterial,
+    ) {
+    }
+}
+
+/// Type of rigid body to add to a Tiled object.
+///
+/// Can be set from Tiled using a custom property, see [TiledPhysicsSettings::rigid_body_property].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TiledRigidBody {
+    /// A rigid body that does not move.
+    #[default]
+    Static,
+    /// A rigid body affected by forces and collisions.
+    Dynamic,
+    /// A rigid body only moved by its velocity or by user code.
+    Kinematic,
+}
+
+impl TiledRigidBody {
+    /// Try to get a [TiledRigidBody] from a Tiled [PropertyValue].
+    ///
+    /// Property can either be a string (case-insensitive variant name) or an integer
+    /// (variant index, in declaration order).
+    pub fn from_property(value: &PropertyValue) -> Option<Self> {
+        match value {
+            PropertyValue::StringValue(s) => match s.trim().to_lowercase().as_str() {
+                "static" => Some(Self::Static),
+                "dynamic" => Some(Self::Dynamic),
+                "kinematic" => Some(Self::Kinematic),
+                _ => None,
+            },
+            PropertyValue::IntValue(0) => Some(Self::Static),
+            PropertyValue::IntValue(1) => Some(Self::Dynamic),
+            PropertyValue::IntValue(2) => Some(Self::Kinematic),
+            _ => None,
+        }
+    }
+}
+
+/// Physics material of a collider.
+///
+/// Can be set from Tiled using custom properties, on objects or on tiles collision objects,
+/// see [TiledPhysicsSettings::friction_property], [TiledPhysicsSettings::restitution_property]
+/// and [TiledPhysicsSettings::sensor_property].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TiledColliderMaterial {
+    /// Friction coefficient of the collider, if set.
+    pub friction: Option<f32>,
+    /// Restitution coefficient of the collider, if set.
+    pub restitution: Option<f32>,
+    /// Whether the collider is a sensor, which detects collisions without responding to them.
+    pub sensor: bool,
+}
+
+impl TiledColliderMaterial {
+    /// Try to get a [TiledColliderMaterial] from Tiled [Properties].
+    ///
+    /// Friction and restitution properties can either be floats or integers,
+    /// sensor property should be a boolean.
+    /// Returns `None` if none of these properties are set.
+    pub fn from_properties<T: TiledPhysicsBackend + Default>(
+        properties: &Properties,
+        settings: &TiledPhysicsSettings<T>,
+    ) -> Option<Self> {
+        let number = |property: &str| match properties.get(property)? {
+            PropertyValue::FloatValue(v) => Some(*v),
+            PropertyValue::IntValue(v) => Some(*v as f32),
+            _ => None,
+        };
+        let sensor = match properties.get(&settings.sensor_property) {
+            Some(PropertyValue::BoolValue(v)) => Some(*v),
+            _ => None,
+        };
+        let material = Self {
+            friction: number(&settings.friction_property),
+            restitution: number(&settings.restitution_property),
+            sensor: sensor.unwrap_or_default(),
+        };
+        (material.friction.is_some() || material.restitution.is_some() || sensor.is_some())
+            .then_some(material)
+    }
 }
 
 /// Physics related settings.
-#[derive(Clone, Component, Default)]
+///
+/// Should be added to the map entity: a default one will be inserted if it's not there.
+/// It can also be added to a layer entity, in which case it will override map-level
+/// settings for this layer.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// let settings = TiledPhysicsSettings::<TiledPhysicsAvianBackend>::default()
+///     .with_objects_layer_filter(ObjectNames::Names(vec![String::from("colliders")]))
+///     .with_tiles_objects_filter(ObjectNames::None);
+/// ```
+#[derive(Clone, Component)]
 pub struct TiledPhysicsSettings<T: TiledPhysicsBackend + Default> {
     /// Specify which Tiled object to add colliders for using their layer name.
     ///
@@ -70,10 +192,103 @@ pub struct TiledPhysicsSettings<T: TiledPhysicsBackend + Default> {
     /// Colliders will be automatically added for all tiles collision objects whose name matches this filter.
     /// By default, we add colliders for all collision objects.
     pub tiles_objects_filter: ObjectNames,
+    /// Name of the Tiled object custom property used to set its rigid body type.
+    ///
+    /// Property value should match one of the [TiledRigidBody] variants (`Static`, `Dynamic` or `Kinematic`).
+    /// Objects without this property will not get any rigid body.
+    /// By default, we look for a property named `rigid_body`.
+    pub rigid_body_property: String,
+    /// Name of the Tiled custom property used to set a collider friction coefficient.
+    ///
+    /// Can be set on objects or on tiles collision objects, see [TiledColliderMaterial].
+    /// By default, we look for a property named `friction`.
+    pub friction_property: String,
+    /// Name of the Tiled custom property used to set a collider restitution coefficient.
+    ///
+    /// Can be set on objects or on tiles collision objects, see [TiledColliderMaterial].
+    /// By default, we look for a property named `restitution`.
+    pub restitution_property: String,
+    /// Name of the Tiled custom property used to make a collider a sensor.
+    ///
+    /// Can be set on objects or on tiles collision objects, see [TiledColliderMaterial].
+    /// By default, we look for a property named `sensor`.
+    pub sensor_property: String,
     /// Physics backend to use for adding colliders.
     pub backend: T,
 }
 
+impl<T: TiledPhysicsBackend + Default> Default for TiledPhysicsSettings<T> {
+    fn default() -> Self {
+        Self {
+            objects_layer_filter: ObjectNames::default(),
+            objects_filter: ObjectNames::default(),
+            tiles_layer_filter: ObjectNames::default(),
+            tiles_objects_filter: ObjectNames::default(),
+            rigid_body_property: String::from("rigid_body"),
+            friction_property: String::from("friction"),
+            restitution_property: String::from("restitution"),
+            sensor_property: String::from("sensor"),
+            backend: T::default(),
+        }
+    }
+}
+
+impl<T: TiledPhysicsBackend + Default> TiledPhysicsSettings<T> {
+    /// Set [TiledPhysicsSettings::objects_layer_filter].
+    pub fn with_objects_layer_filter(mut self, filter: ObjectNames) -> Self {
+        self.objects_layer_filter = filter;
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::objects_filter].
+    pub fn with_objects_filter(mut self, filter: ObjectNames) -> Self {
+        self.objects_filter = filter;
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::tiles_layer_filter].
+    pub fn with_tiles_layer_filter(mut self, filter: ObjectNames) -> Self {
+        self.tiles_layer_filter = filter;
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::tiles_objects_filter].
+    pub fn with_tiles_objects_filter(mut self, filter: ObjectNames) -> Self {
+        self.tiles_objects_filter = filter;
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::rigid_body_property].
+    pub fn with_rigid_body_property(mut self, property: impl Into<String>) -> Self {
+        self.rigid_body_property = property.into();
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::friction_property].
+    pub fn with_friction_property(mut self, property: impl Into<String>) -> Self {
+        self.friction_property = property.into();
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::restitution_property].
+    pub fn with_restitution_property(mut self, property: impl Into<String>) -> Self {
+        self.restitution_property = property.into();
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::sensor_property].
+    pub fn with_sensor_property(mut self, property: impl Into<String>) -> Self {
+        self.sensor_property = property.into();
+        self
+    }
+
+    /// Set [TiledPhysicsSettings::backend].
+    pub fn with_backend(mut self, backend: T) -> Self {
+        self.backend = backend;
+        self
+    }
+}
+
 /// Physics plugin.
 ///
 /// Must be added to your app in order to automatically spawn physics colliders using the provided [TiledPhysicsBackend].
@@ -117,20 +332,42 @@ fn default_physics_settings<
     }
 }
 
+/// Retrieve [TiledPhysicsSettings] for a given layer.
+///
+/// Settings from the layer entity take precedence over the ones from the map entity.
+fn get_settings<'a, T: TiledPhysicsBackend + Default + 'static + Sync + Send>(
+    q_settings: &'a Query<&TiledPhysicsSettings<T>>,
+    layer: Entity,
+    map: Entity,
+) -> Option<&'a TiledPhysicsSettings<T>> {
+    q_settings.get(layer).or_else(|_| q_settings.get(map)).ok()
+}
+
 fn collider_from_object<
     T: TiledPhysicsBackend + Default + 'static + std::marker::Sync + std::marker::Send,
 >(
     trigger: Trigger<TiledObjectCreated>,
     mut commands: Commands,
     map_asset: Res<Assets<TiledMap>>,
-    q_settings: Query<&TiledPhysicsSettings<T>, With<TiledMapMarker>>,
+    q_settings: Query<&TiledPhysicsSettings<T>>,
 ) {
     let layer = trigger.event().layer(&map_asset);
     let object = trigger.event().object(&map_asset);
-    let Ok(settings) = q_settings.get(trigger.event().map) else {
+    let Some(settings) = get_settings(&q_settings, trigger.event().layer, trigger.event().map)
+    else {
         return;
     };
 
+    if let Some(rigid_body) = object
+        .properties
+        .get(&settings.rigid_body_property)
+        .and_then(TiledRigidBody::from_property)
+    {
+        settings
+            .backend
+            .insert_rigid_body(&mut commands, trigger.event().object, rigid_body);
+    }
+
     if ObjectNameFilter::from(&settings.objects_layer_filter).contains(&layer.name)
         && ObjectNameFilter::from(&settings.objects_filter).contains(&object.name)
     {
@@ -147,6 +384,7 @@ fn collider_from_object<
                 ),
             },
             Vec2::ZERO,
+            TiledColliderMaterial::from_properties(&object.properties, settings),
         );
     }
 }
@@ -157,7 +395,7 @@ fn collider_from_tile<
     trigger: Trigger<TiledSpecialTileCreated>,
     mut commands: Commands,
     map_asset: Res<Assets<TiledMap>>,
-    q_settings: Query<&TiledPhysicsSettings<T>, With<TiledMapMarker>>,
+    q_settings: Query<&TiledPhysicsSettings<T>>,
 ) {
     if let Some(tile_data) = trigger.event().tile(&map_asset).get_tile() {
         if tile_data.collision.is_none() {
@@ -165,7 +403,8 @@ fn collider_from_tile<
         }
     };
 
-    let Ok(settings) = q_settings.get(trigger.event().map) else {
+    let Some(settings) = get_settings(&q_settings, trigger.event().layer, trigger.event().map)
+    else {
         return;
     };
 
@@ -217,8 +456,61 @@ fn collider_from_tile<
                         x: object_data.x - map.tile_width as f32 / 2.,
                         y: (map.tile_height as f32 - object_data.y) - map.tile_height as f32 / 2.,
                     },
+                    // Tiles collision objects can have their own custom properties
+                    TiledColliderMaterial::from_properties(&object_data.properties, settings),
                 );
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct NoBackend;
+
+    impl TiledPhysicsBackend for NoBackend {
+        fn spawn_collider(
+            &self,
+            _commands: &mut Commands,
+            _map: &Map,
+            _collider_source: &TiledColliderSource,
+        ) -> Option<TiledColliderSpawnInfos> {
+            None
+        }
+    }
+
+    #[test]
+    fn collider_material_from_properties() {
+        let settings = TiledPhysicsSettings::<NoBackend>::default().with_sensor_property("trigger");
+
+        let properties = Properties::from([
+            (String::from("friction"), PropertyValue::FloatValue(0.5)),
+            (String::from("restitution"), PropertyValue::IntValue(1)),
+            (String::from("trigger"), PropertyValue::BoolValue(true)),
+        ]);
+        assert_eq!(
+            TiledColliderMaterial::from_properties(&properties, &settings),
+            Some(TiledColliderMaterial {
+                friction: Some(0.5),
+                restitution: Some(1.),
+                sensor: true,
+            })
+        );
+
+        // Properties with unexpected types or names are ignored
+        let properties = Properties::from([
+            (
+                String::from("friction"),
+                PropertyValue::StringValue(String::from("high")),
+            ),
+            (String::from("sensor"), PropertyValue::BoolValue(true)),
+        ]);
+        assert_eq!(
+            TiledColliderMaterial::from_properties(&properties, &settings),
+            None
+        );
+    }
+}
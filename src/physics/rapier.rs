@@ -57,9 +57,135 @@ impl TiledPhysicsBackend for TiledPhysicsRapierBackend {
             name: format!("Rapier[{}]", object_data.name),
             entity: commands.spawn(collider).id(),
             position: pos,
-            rotation: -object_data.rotation,
+            rotation: match collider_source.ty {
+                // Objects entities are already rotated
+                TiledColliderSourceType::Object { .. } => 0.,
+                TiledColliderSourceType::Tile { .. } => -object_data.rotation,
+            },
         })
     }
+
+    fn insert_rigid_body(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        rigid_body: TiledRigidBody,
+    ) {
+        commands.entity(entity).insert(match rigid_body {
+            TiledRigidBody::Static => RigidBody::Fixed,
+            TiledRigidBody::Dynamic => RigidBody::Dynamic,
+            TiledRigidBody::Kinematic => RigidBody::KinematicPositionBased,
+        });
+    }
+
+    fn insert_collider_material(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        material: TiledColliderMaterial,
+    ) {
+        let mut entity = commands.entity(entity);
+        if let Some(friction) = material.friction {
+            entity.insert(Friction::coefficient(friction));
+        }
+        if let Some(restitution) = material.restitution {
+            entity.insert(Restitution::coefficient(restitution));
+        }
+        if material.sensor {
+            entity.insert(Sensor);
+        }
+    }
+}
+
+/// Kind of joint created from a [TiledRapierJoint].
+#[cfg(feature = "user_properties")]
+#[derive(Reflect, Default, Copy, Clone, Debug, PartialEq)]
+#[reflect(Default, Debug)]
+pub enum TiledJointKind {
+    /// Revolute joint: bodies can only rotate around the anchor.
+    #[default]
+    Revolute,
+    /// Prismatic joint: bodies can only translate along provided axis.
+    Prismatic {
+        /// Axis of the translation, relative to the other object.
+        axis: Vec2,
+    },
+    /// Fixed joint: bodies cannot move relative to each other.
+    Fixed,
+}
+
+/// [Component] describing a Rapier joint between two Tiled objects.
+///
+/// It is meant to be added to a Tiled object using custom properties:
+/// `other` should reference the object to attach to.
+/// Both objects should have a rigid body, see [TiledPhysicsSettings::rigid_body_property].
+/// An [ImpulseJoint] will then be inserted on the object, as long as [TiledRapierJointsPlugin] is added to your application.
+#[cfg(feature = "user_properties")]
+#[derive(Component, Reflect, Default, Copy, Clone, Debug)]
+#[reflect(Component, Default, Debug)]
+pub struct TiledRapierJoint {
+    /// Kind of joint.
+    pub kind: TiledJointKind,
+    /// Object to attach to.
+    pub other: Option<Entity>,
+    /// Joint anchor, relative to this object origin.
+    pub anchor: Vec2,
+    /// Joint anchor, relative to the other object origin.
+    pub other_anchor: Vec2,
+}
+
+/// [Plugin] creating Rapier joints from [TiledRapierJoint] custom properties.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// App::new()
+///     .add_plugins(TiledPhysicsPlugin::<TiledPhysicsRapierBackend>::default())
+///     .add_plugins(TiledRapierJointsPlugin);
+/// ```
+#[cfg(feature = "user_properties")]
+#[derive(Default)]
+pub struct TiledRapierJointsPlugin;
+
+#[cfg(feature = "user_properties")]
+impl Plugin for TiledRapierJointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TiledJointKind>()
+            .register_type::<TiledRapierJoint>()
+            .add_systems(Update, spawn_joints);
+    }
+}
+
+#[cfg(feature = "user_properties")]
+fn spawn_joints(
+    mut commands: Commands,
+    q_joints: Query<(Entity, &TiledRapierJoint), Changed<TiledRapierJoint>>,
+) {
+    for (entity, joint) in q_joints.iter() {
+        let Some(other) = joint.other else {
+            warn!("Joint on entity {entity:?} does not reference another object, skip it");
+            continue;
+        };
+        let data: TypedJoint = match joint.kind {
+            TiledJointKind::Revolute => RevoluteJointBuilder::new()
+                .local_anchor1(joint.other_anchor)
+                .local_anchor2(joint.anchor)
+                .into(),
+            TiledJointKind::Prismatic { axis } => PrismaticJointBuilder::new(axis)
+                .local_anchor1(joint.other_anchor)
+                .local_anchor2(joint.anchor)
+                .into(),
+            TiledJointKind::Fixed => FixedJointBuilder::new()
+                .local_anchor1(joint.other_anchor)
+                .local_anchor2(joint.anchor)
+                .into(),
+        };
+        commands
+            .entity(entity)
+            .insert(ImpulseJoint::new(other, data));
+    }
 }
 
 fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vect, Collider)> {
@@ -71,7 +197,10 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vect, Collider)> {
             Some((pos, shape))
         }
         ObjectShape::Ellipse { width, height } => {
-            let shape = if width > height {
+            // Rapier does not have an ellipse shape: use a ball for circles and a capsule otherwise
+            let shape = if width == height {
+                Collider::ball(width / 2.)
+            } else if width > height {
                 Collider::capsule(
                     Vec2::new((-width + height) / 2., 0.),
                     Vec2::new((width - height) / 2., 0.),
@@ -88,9 +217,12 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vect, Collider)> {
             let pos = Vect::new(width / 2., -height / 2.);
             Some((pos, shape))
         }
-        ObjectShape::Polyline { points } => {
+        ObjectShape::Polyline { .. } => {
             let shape = Collider::polyline(
-                points.iter().map(|(x, y)| Vect::new(*x, -*y)).collect(),
+                object_shape_vertices(shape)
+                    .into_iter()
+                    .map(|p| Vect::new(p.x, -p.y))
+                    .collect(),
                 None,
             );
             Some((Vect::ZERO, shape))
@@ -100,9 +232,9 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vect, Collider)> {
                 return None;
             }
 
-            let points = points
-                .iter()
-                .map(|(x, y)| Vect::new(*x, -*y))
+            let points = object_shape_vertices(shape)
+                .into_iter()
+                .map(|p| Vect::new(p.x, -p.y))
                 .collect::<Vec<_>>();
 
             let indices = (0..points.len() as u32 - 1)
@@ -117,3 +249,28 @@ fn get_position_and_collider(shape: &ObjectShape) -> Option<(Vect, Collider)> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polyline_is_not_closed() {
+        let points = vec![(0., 0.), (10., 0.), (10., 10.)];
+
+        let (_, polyline) = get_position_and_collider(&ObjectShape::Polyline {
+            points: points.clone(),
+        })
+        .unwrap();
+        let (_, polygon) = get_position_and_collider(&ObjectShape::Polygon { points }).unwrap();
+
+        let polyline = polyline.as_polyline().unwrap();
+        let polygon = polygon.as_polyline().unwrap();
+
+        assert_eq!(polyline.raw.vertices().len(), polygon.raw.vertices().len());
+        // An open chain has one segment less than the matching closed polygon
+        assert_eq!(polyline.raw.indices().len(), 2);
+        assert_eq!(polygon.raw.indices().len(), 3);
+        assert_eq!(polygon.raw.indices()[2], [2, 0]);
+    }
+}
@@ -0,0 +1,212 @@
+//! This module contains utilities to pick tiles and objects from a world position.
+
+use crate::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_ecs_tilemap::prelude::*;
+use tiled::{LayerType, ObjectShape};
+
+/// Result of a [TiledPick::pick] call.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TiledPickResult {
+    /// [TilePos] located at the picked position, if any.
+    pub tile_pos: Option<TilePos>,
+    /// Objects entities whose shape contains the picked position.
+    pub objects: Vec<Entity>,
+}
+
+/// [SystemParam] to retrieve the tile and objects located at a given world position.
+///
+/// Works with all map orientations: inverse projection for isometric and hexagonal maps is handled for you.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::{prelude::*, window::PrimaryWindow};
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// fn pick(
+///     pick: TiledPick,
+///     q_window: Query<&Window, With<PrimaryWindow>>,
+///     q_camera: Query<(&Camera, &GlobalTransform)>,
+///     q_maps: Query<Entity, With<TiledMapMarker>>,
+/// ) {
+///     let (camera, camera_transform) = q_camera.single();
+///     let Some(world_position) = q_window
+///         .single()
+///         .cursor_position()
+///         .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+///     else {
+///         return;
+///     };
+///     for map in q_maps.iter() {
+///         if let Some(result) = pick.pick(map, world_position) {
+///             info!("Tile {:?}, objects {:?}", result.tile_pos, result.objects);
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct TiledPick<'w, 's> {
+    maps: Res<'w, Assets<TiledMap>>,
+    q_maps: Query<
+        'w,
+        's,
+        (
+            &'static TiledMapHandle,
+            &'static TiledIdStorage,
+            &'static TiledMapSettings,
+            &'static GlobalTransform,
+        ),
+    >,
+    q_layers: Query<'w, 's, &'static GlobalTransform, With<TiledMapLayer>>,
+}
+
+impl TiledPick<'_, '_> {
+    /// Pick the tile and objects of provided map [Entity] located at given world position.
+    ///
+    /// Objects are picked using their shape as defined in Tiled, so moving an object [Entity]
+    /// at runtime will not be taken into account.
+    /// Only objects with an area (rectangles, ellipses, polygons and texts) can be picked.
+    /// Objects edges are considered inside.
+    /// Objects from layers nested in groups are not picked, since group layers are not spawned yet.
+    ///
+    /// [TiledPickResult::tile_pos] is always `None` for infinite maps,
+    /// see [TiledMap::tile_at_world_position].
+    ///
+    /// Returns `None` if provided [Entity] is not a loaded map.
+    pub fn pick(&self, map_entity: Entity, world_position: Vec2) -> Option<TiledPickResult> {
+        let (map_handle, storage, settings, map_transform) = self.q_maps.get(map_entity).ok()?;
+        let tiled_map = self.maps.get(&map_handle.0)?;
+
+        let tile_pos = tiled_map.tile_at_world_position(world_position, map_transform, settings);
+
+        let mut objects = vec![];
+        for layer in tiled_map.map.layers() {
+            let LayerType::Objects(object_layer) = layer.layer_type() else {
+                continue;
+            };
+            let Some(layer_transform) = storage
+                .layers
+                .get(&layer.id())
+                .and_then(|e| self.q_layers.get(*e).ok())
+            else {
+                continue;
+            };
+            for object in object_layer.objects() {
+                if matches!(
+                    object.shape,
+                    ObjectShape::Point(_, _) | ObjectShape::Polyline { .. }
+                ) {
+                    continue;
+                }
+                let Some(entity) = storage.objects.get(&object.id()) else {
+                    continue;
+                };
+                let vertices = tiled_map.object_world_vertices(&object, layer_transform);
+                if polygon_contains(&vertices, world_position) {
+                    objects.push(*entity);
+                }
+            }
+        }
+
+        Some(TiledPickResult { tile_pos, objects })
+    }
+}
+
+// Even-odd rule point in polygon test, points on edges are inside
+fn polygon_contains(vertices: &[Vec2], point: Vec2) -> bool {
+    let edges = || {
+        vertices
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (*a, vertices[(i + 1) % vertices.len()]))
+    };
+    if edges().any(|(a, b)| segment_contains(a, b, point)) {
+        return true;
+    }
+    let mut inside = false;
+    for (a, b) in edges() {
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn segment_contains(a: Vec2, b: Vec2, point: Vec2) -> bool {
+    let (ab, ap) = (b - a, point - a);
+    ab.perp_dot(ap).abs() <= 1e-4 * ab.length().max(1.)
+        && ap.dot(ab) >= 0.
+        && ap.dot(ab) <= ab.length_squared()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polygon_contains_convex() {
+        let square = [
+            Vec2::new(0., 0.),
+            Vec2::new(10., 0.),
+            Vec2::new(10., 10.),
+            Vec2::new(0., 10.),
+        ];
+        assert!(polygon_contains(&square, Vec2::new(5., 5.)));
+        assert!(polygon_contains(&square, Vec2::new(0.1, 9.9)));
+        assert!(!polygon_contains(&square, Vec2::new(-0.1, 5.)));
+        assert!(!polygon_contains(&square, Vec2::new(5., 10.1)));
+    }
+
+    #[test]
+    fn polygon_contains_concave() {
+        // L-shaped polygon, missing its top right quarter
+        let l_shape = [
+            Vec2::new(0., 0.),
+            Vec2::new(10., 0.),
+            Vec2::new(10., 5.),
+            Vec2::new(5., 5.),
+            Vec2::new(5., 10.),
+            Vec2::new(0., 10.),
+        ];
+        assert!(polygon_contains(&l_shape, Vec2::new(2., 8.)));
+        assert!(polygon_contains(&l_shape, Vec2::new(8., 2.)));
+        assert!(!polygon_contains(&l_shape, Vec2::new(8., 8.)));
+        assert!(!polygon_contains(&l_shape, Vec2::new(11., 2.)));
+    }
+
+    #[test]
+    fn polygon_contains_rotated() {
+        // 10x10 square rotated by 45 degrees around its center
+        let rotation = Rot2::degrees(45.);
+        let center = Vec2::new(5., 5.);
+        let square = [
+            Vec2::new(0., 0.),
+            Vec2::new(10., 0.),
+            Vec2::new(10., 10.),
+            Vec2::new(0., 10.),
+        ]
+        .map(|v| center + rotation * (v - center));
+        assert!(polygon_contains(&square, center));
+        // Outside of the axis-aligned square but inside the rotated one
+        assert!(polygon_contains(&square, Vec2::new(5., 11.)));
+        // Inside of the axis-aligned square but outside the rotated one
+        assert!(!polygon_contains(&square, Vec2::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn polygon_contains_edges() {
+        let triangle = [Vec2::new(0., 0.), Vec2::new(10., 0.), Vec2::new(0., 10.)];
+        // On edges
+        assert!(polygon_contains(&triangle, Vec2::new(5., 0.)));
+        assert!(polygon_contains(&triangle, Vec2::new(0., 5.)));
+        assert!(polygon_contains(&triangle, Vec2::new(5., 5.)));
+        // On vertices
+        assert!(polygon_contains(&triangle, Vec2::new(10., 0.)));
+        assert!(polygon_contains(&triangle, Vec2::new(0., 0.)));
+        // On edges lines, but outside of the polygon
+        assert!(!polygon_contains(&triangle, Vec2::new(11., 0.)));
+        assert!(!polygon_contains(&triangle, Vec2::new(-1., 11.)));
+    }
+}
@@ -2,8 +2,11 @@ use super::load::DeserializedProperties;
 use bevy::ecs::reflect::ReflectBundle;
 use bevy::ecs::system::EntityCommands;
 use bevy::ecs::world::Command;
-use bevy::prelude::{AppTypeRegistry, Entity, ReflectComponent, ReflectResource, World};
+use bevy::prelude::{
+    AppTypeRegistry, Entity, FromReflect, ReflectComponent, ReflectResource, Transform, World,
+};
 use bevy::reflect::{PartialReflect, TypeRegistry};
+use std::any::TypeId;
 use std::ops::Deref;
 
 pub(crate) trait PropertiesCommandExt {
@@ -59,6 +62,17 @@ fn insert_reflect(
         panic!("error[B0003]: Could not insert a reflected property (of type {type_path}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/#b0003");
     };
 
+    // A Transform property is composed with the Transform computed when spawning the entity
+    if type_info.type_id() == TypeId::of::<Transform>() {
+        if let (Some(offset), Some(mut transform)) = (
+            Transform::from_reflect(&*property),
+            entity.get_mut::<Transform>(),
+        ) {
+            *transform = *transform * offset;
+            return;
+        }
+    }
+
     if let Some(reflect_component) = type_registration.data::<ReflectComponent>() {
         reflect_component.insert(&mut entity, &*property, type_registry);
     } else if let Some(reflect_bundle) = type_registration.data::<ReflectBundle>() {
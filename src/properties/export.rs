@@ -1,6 +1,7 @@
 use crate::properties::types_json::{
     Class, Enum, FieldType, Member, StorageType, TypeData, TypeExport, UseAs,
 };
+use crate::properties::{TiledClassColor, TiledColorFormat, TiledFlags, TiledSkip};
 use bevy::ecs::reflect::ReflectBundle;
 use bevy::reflect::{
     ArrayInfo, EnumInfo, NamedField, StructInfo, TupleInfo, TupleStructInfo, TypeInfo,
@@ -9,6 +10,8 @@ use bevy::reflect::{
 use bevy::utils::hashbrown::HashMap;
 use bevy::{prelude::*, reflect::ReflectRef};
 use std::borrow::Cow;
+use std::ops::{Range, RangeInclusive};
+use std::time::Duration;
 use thiserror::Error;
 
 const DEFAULT_COLOR: &str = "#000000";
@@ -34,6 +37,7 @@ enum ExportConversionError {
 pub(crate) struct TypeExportRegistry {
     types: HashMap<&'static str, Vec<TypeExport>>,
     id: u32,
+    color_format: TiledColorFormat,
 }
 
 impl TypeExportRegistry {
@@ -45,8 +49,18 @@ impl TypeExportRegistry {
     }
 
     pub(crate) fn from_registry(registry: &TypeRegistry) -> Self {
+        Self::from_registry_with_color_format(registry, TiledColorFormat::default())
+    }
+
+    pub(crate) fn from_registry_with_color_format(
+        registry: &TypeRegistry,
+        color_format: TiledColorFormat,
+    ) -> Self {
         let mut deps = vec![];
-        let mut out = Self::default();
+        let mut out = Self {
+            color_format,
+            ..default()
+        };
         for t in registry.iter() {
             if t.data::<ReflectComponent>().is_some()
                 || t.data::<ReflectBundle>().is_some()
@@ -130,6 +144,9 @@ impl TypeExportRegistry {
             TypeInfo::TupleStruct(info) => {
                 self.generate_tuple_struct_export(info, registry, default_value, use_as)
             }
+            TypeInfo::Struct(info) if is_flags(registration.type_info()) => {
+                self.generate_flags_export(info)
+            }
             TypeInfo::Struct(info) => {
                 self.generate_struct_export(info, registry, default_value, use_as)
             }
@@ -137,13 +154,32 @@ impl TypeExportRegistry {
                 self.generate_tuple_export(info, registry, default_value, use_as)
             }
             TypeInfo::List(_) => Err(ExportConversionError::ListUnsupported),
+            // Arrays of chars are exported as a single string
+            TypeInfo::Array(_) if is_char_array(registration.type_info()) => Ok(vec![]),
             TypeInfo::Array(info) => self.generate_array_export(info, registry, use_as),
             TypeInfo::Map(_) => Err(ExportConversionError::MapUnsupported),
             TypeInfo::Enum(info) => self.generate_enum_export(info, registry, use_as),
-            TypeInfo::Opaque(_) => Ok(vec![]),
+            TypeInfo::Opaque(info) => match range_item_field(info.type_path()) {
+                Some(field) => self.generate_range_export(info.type_path(), field),
+                None => Ok(vec![]),
+            },
             TypeInfo::Set(_) => Err(ExportConversionError::SetUnsupported),
         };
 
+        // Apply the class color set using the TiledClassColor attribute
+        let out = out.map(|mut exports| {
+            if let Some(color) = class_color(registration.type_info()) {
+                for export in exports.iter_mut() {
+                    if let TypeData::Class(class) = &mut export.type_data {
+                        if export.name == registration.type_info().type_path() {
+                            class.color = color.0.to_string();
+                        }
+                    }
+                }
+            }
+            exports
+        });
+
         if out.is_ok() {
             let mut new_deps = dependencies(registration, registry);
             if new_deps.iter().all(|n| {
@@ -200,6 +236,7 @@ impl TypeExportRegistry {
                 draw_fill: true,
                 members: info
                     .iter()
+                    .filter(|s| !s.custom_attributes().contains::<TiledSkip>())
                     .map(|s| {
                         let (type_field, property_type) =
                             type_to_field(registry.get(s.type_id()).unwrap())?;
@@ -210,6 +247,7 @@ impl TypeExportRegistry {
                             value: unnamed_field_json_value(
                                 default_value.map(|v| v.as_partial_reflect()),
                                 s,
+                                self.color_format,
                             ),
                         })
                     })
@@ -276,6 +314,7 @@ impl TypeExportRegistry {
                             value: unnamed_field_json_value(
                                 default_value.map(|v| v.as_partial_reflect()),
                                 s,
+                                self.color_format,
                             ),
                         })
                     })
@@ -302,6 +341,7 @@ impl TypeExportRegistry {
                 draw_fill: true,
                 members: info
                     .iter()
+                    .filter(|s| !s.custom_attributes().contains::<TiledSkip>())
                     .map(|s| {
                         let (type_field, property_type) =
                             type_to_field(registry.get(s.type_id()).unwrap())?;
@@ -312,6 +352,7 @@ impl TypeExportRegistry {
                             value: named_field_json_value(
                                 default_value.map(|v| v.as_partial_reflect()),
                                 s,
+                                self.color_format,
                             ),
                         })
                     })
@@ -322,6 +363,49 @@ impl TypeExportRegistry {
         Ok(vec![root])
     }
 
+    fn generate_range_export(
+        &mut self,
+        type_path: &str,
+        field: FieldType,
+    ) -> ExportConversionResult {
+        let root = TypeExport {
+            id: self.next_id(),
+            name: type_path.to_string(),
+            type_data: TypeData::Class(Class {
+                use_as: USE_AS_PROPERTY.to_vec(),
+                color: DEFAULT_COLOR.to_string(),
+                draw_fill: true,
+                members: ["start", "end"]
+                    .iter()
+                    .map(|name| Member {
+                        name: name.to_string(),
+                        property_type: None,
+                        type_field: field,
+                        value: serde_json::Value::default(),
+                    })
+                    .collect(),
+            }),
+        };
+
+        Ok(vec![root])
+    }
+
+    fn generate_flags_export(&mut self, info: &StructInfo) -> ExportConversionResult {
+        if let Some(field) = info.iter().find(|f| !f.is::<bool>()) {
+            return Err(ExportConversionError::UnsupportedValue(field.type_path()));
+        }
+
+        Ok(vec![TypeExport {
+            id: self.next_id(),
+            name: info.type_path().to_string(),
+            type_data: TypeData::Enum(Enum {
+                storage_type: StorageType::Int,
+                values_as_flags: true,
+                values: info.iter().map(|f| f.name().to_string()).collect(),
+            }),
+        }])
+    }
+
     fn generate_enum_export(
         &mut self,
         info: &EnumInfo,
@@ -469,11 +553,20 @@ impl TypeExportRegistry {
     }
 }
 
-fn value_to_json(value: &dyn PartialReflect) -> serde_json::Value {
+fn value_to_json(value: &dyn PartialReflect, color_format: TiledColorFormat) -> serde_json::Value {
     let Some(type_info) = value.get_represented_type_info() else {
         return serde_json::Value::default();
     };
 
+    // Durations are exported as a number of seconds
+    if let Some(duration) = value.try_downcast_ref::<Duration>() {
+        return serde_json::json!(duration.as_secs_f32());
+    }
+
+    if let Some(range) = range_to_json(value) {
+        return range;
+    }
+
     match (type_info.type_path(), type_info, value.reflect_ref()) {
         ("bool", _, ReflectRef::Opaque(v)) => {
             serde_json::json!(*v.try_downcast_ref::<bool>().unwrap())
@@ -524,8 +617,12 @@ fn value_to_json(value: &dyn PartialReflect) -> serde_json::Value {
         }
         ("bevy_color::color::Color", _, _) => {
             let c = value.try_downcast_ref::<Color>().unwrap();
-            serde_json::json!(format!("#{:08x}", c.to_linear().as_u32()))
+            serde_json::json!(color_format.to_tiled(c))
         }
+        (_, _, ReflectRef::Array(v)) if is_char_array(type_info) => serde_json::json!(v
+            .iter()
+            .filter_map(|c| c.try_downcast_ref::<char>())
+            .collect::<String>()),
         (_, TypeInfo::Enum(info), ReflectRef::Enum(v)) => {
             if info.iter().all(|v| matches!(v, VariantInfo::Unit(_))) {
                 serde_json::json!(v.variant_name())
@@ -533,16 +630,33 @@ fn value_to_json(value: &dyn PartialReflect) -> serde_json::Value {
                 serde_json::Value::default()
             }
         }
+        (_, TypeInfo::Struct(info), ReflectRef::Struct(v)) if is_flags(type_info) => {
+            let flags = info
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| {
+                    v.field(f.name())
+                        .and_then(|f| f.try_downcast_ref::<bool>())
+                        .is_some_and(|b| *b)
+                })
+                .fold(0, |flags, (i, _)| flags | (1 << i));
+            serde_json::json!(flags)
+        }
         (_, TypeInfo::Struct(info), _) => info
             .iter()
-            .map(|s| (s.name(), named_field_json_value(Some(value), s)))
+            .map(|s| {
+                (
+                    s.name(),
+                    named_field_json_value(Some(value), s, color_format),
+                )
+            })
             .collect(),
         (_, TypeInfo::Tuple(info), _) => info
             .iter()
             .map(|s| {
                 (
                     s.index().to_string(),
-                    unnamed_field_json_value(Some(value), s),
+                    unnamed_field_json_value(Some(value), s, color_format),
                 )
             })
             .collect(),
@@ -551,7 +665,7 @@ fn value_to_json(value: &dyn PartialReflect) -> serde_json::Value {
             .map(|s| {
                 (
                     s.index().to_string(),
-                    unnamed_field_json_value(Some(value), s),
+                    unnamed_field_json_value(Some(value), s, color_format),
                 )
             })
             .collect(),
@@ -568,12 +682,13 @@ fn value_to_json(value: &dyn PartialReflect) -> serde_json::Value {
 fn named_field_json_value(
     parent_value: Option<&dyn PartialReflect>,
     field: &NamedField,
+    color_format: TiledColorFormat,
 ) -> serde_json::Value {
     match parent_value {
         Some(v) => match v.reflect_ref() {
             ReflectRef::Struct(t) => t
                 .field(field.name())
-                .map(value_to_json)
+                .map(|v| value_to_json(v, color_format))
                 .unwrap_or(serde_json::Value::default()),
             _ => serde_json::Value::default(),
         },
@@ -584,16 +699,17 @@ fn named_field_json_value(
 fn unnamed_field_json_value(
     parent_value: Option<&dyn PartialReflect>,
     field: &UnnamedField,
+    color_format: TiledColorFormat,
 ) -> serde_json::Value {
     match parent_value {
         Some(v) => match v.reflect_ref() {
             ReflectRef::TupleStruct(t) => (*t)
                 .field(field.index())
-                .map(value_to_json)
+                .map(|v| value_to_json(v, color_format))
                 .unwrap_or(serde_json::Value::default()),
             ReflectRef::Tuple(t) => (*t)
                 .field(field.index())
-                .map(value_to_json)
+                .map(|v| value_to_json(v, color_format))
                 .unwrap_or(serde_json::Value::default()),
             _ => serde_json::Value::default(),
         },
@@ -610,6 +726,12 @@ fn type_to_field(
     } else if matches!(info, TypeInfo::Map(_)) {
         return Err(ExportConversionError::MapUnsupported);
     }
+    if info.is::<Duration>() {
+        return Ok((FieldType::Float, None));
+    }
+    if is_char_array(info) {
+        return Ok((FieldType::String, None));
+    }
     Ok(match info.type_path() {
         "bool" => (FieldType::Bool, None),
         "f32" | "f64" => (FieldType::Float, None),
@@ -625,13 +747,16 @@ fn type_to_field(
         "bevy_color::color::Color" => (FieldType::Color, None),
         "std::path::PathBuf" => (FieldType::File, None),
         f if f.starts_with("bevy_asset::handle::Handle") => (FieldType::File, None),
+        f if range_item_field(f).is_some() => (FieldType::Class, Some(f.to_string())),
         path => {
             if matches!(info, TypeInfo::Opaque(_)) {
                 return Err(ExportConversionError::UnsupportedValue(info.type_path()));
             }
 
             (
-                if is_enum_and_simple(t) {
+                if is_flags(info) {
+                    FieldType::Int
+                } else if is_enum_and_simple(t) {
                     FieldType::String
                 } else {
                     FieldType::Class
@@ -642,6 +767,63 @@ fn type_to_field(
     })
 }
 
+/// Ranges of numeric values are exported as a class with `start` and `end` members.
+///
+/// Returns the type of these members if provided type path is such a range.
+pub(crate) fn range_item_field(type_path: &str) -> Option<FieldType> {
+    let item = type_path
+        .strip_prefix("core::ops::Range<")
+        .or_else(|| type_path.strip_prefix("core::ops::RangeInclusive<"))?
+        .strip_suffix('>')?;
+    match item {
+        "f32" | "f64" => Some(FieldType::Float),
+        "isize" | "i8" | "i16" | "i32" | "i64" | "i128" | "usize" | "u8" | "u16" | "u32"
+        | "u64" | "u128" => Some(FieldType::Int),
+        _ => None,
+    }
+}
+
+fn range_to_json(value: &dyn PartialReflect) -> Option<serde_json::Value> {
+    macro_rules! range_to_json {
+        ($($t:ty),*) => {
+            $(
+                if let Some(range) = value.try_downcast_ref::<Range<$t>>() {
+                    return Some(serde_json::json!({ "start": range.start, "end": range.end }));
+                }
+                if let Some(range) = value.try_downcast_ref::<RangeInclusive<$t>>() {
+                    return Some(serde_json::json!({ "start": range.start(), "end": range.end() }));
+                }
+            )*
+        };
+    }
+    range_to_json!(f32, f64, isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128);
+    None
+}
+
+/// Arrays of chars, such as `[char; 4]`, are handled as a single string.
+pub(crate) fn is_char_array(info: &TypeInfo) -> bool {
+    match info {
+        TypeInfo::Array(info) => info.item_ty().id() == std::any::TypeId::of::<char>(),
+        _ => false,
+    }
+}
+
+pub(crate) fn is_flags(info: &TypeInfo) -> bool {
+    match info {
+        TypeInfo::Struct(info) => info.custom_attributes().contains::<TiledFlags>(),
+        _ => false,
+    }
+}
+
+fn class_color(info: &TypeInfo) -> Option<&TiledClassColor> {
+    match info {
+        TypeInfo::Struct(info) => info.custom_attributes().get::<TiledClassColor>(),
+        TypeInfo::TupleStruct(info) => info.custom_attributes().get::<TiledClassColor>(),
+        TypeInfo::Enum(info) => info.custom_attributes().get::<TiledClassColor>(),
+        _ => None,
+    }
+}
+
 fn is_enum_and_simple(t: &TypeRegistration) -> bool {
     match t.type_info() {
         TypeInfo::Enum(info) => info
@@ -687,6 +869,7 @@ fn dependencies(registration: &TypeRegistration, registry: &TypeRegistry) -> Vec
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::color::ColorToPacked;
     use bevy::render::render_resource::encase::rts_array::Length;
 
     #[test]
@@ -1046,4 +1229,210 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn generate_flags() {
+        #[derive(Reflect, Default)]
+        #[reflect(Default, @TiledFlags)]
+        struct Flags {
+            walkable: bool,
+            flammable: bool,
+        }
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            flags: Flags,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let exports = TypeExportRegistry::from_registry(&registry);
+        let export_type = &exports.types.get(Flags::type_path()).unwrap();
+        assert_eq!(
+            export_type[0].type_data,
+            TypeData::Enum(Enum {
+                storage_type: StorageType::Int,
+                values_as_flags: true,
+                values: vec!["walkable".to_string(), "flammable".to_string()],
+            }),
+        );
+
+        let export_type = &exports.types.get(ComponentA::type_path()).unwrap();
+        let TypeData::Class(class) = &export_type[0].type_data else {
+            panic!("ComponentA should be a class");
+        };
+        assert_eq!(class.members[0].type_field, FieldType::Int);
+        assert_eq!(
+            class.members[0].property_type,
+            Some(Flags::type_path().to_string())
+        );
+        assert_eq!(class.members[0].value, serde_json::json!(0));
+    }
+
+    #[test]
+    fn generate_class_color() {
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default, @TiledClassColor("#ff0000"))]
+        struct ComponentA {
+            health: u32,
+        }
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default)]
+        struct ComponentB {
+            health: u32,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+        registry.register::<ComponentB>();
+
+        let exports = TypeExportRegistry::from_registry(&registry);
+        let color = |type_path: &str| match &exports.types.get(type_path).unwrap()[0].type_data {
+            TypeData::Class(class) => class.color.clone(),
+            TypeData::Enum(_) => panic!("{type_path} should be a class"),
+        };
+        assert_eq!(color(ComponentA::type_path()), "#ff0000");
+        assert_eq!(color(ComponentB::type_path()), DEFAULT_COLOR);
+    }
+
+    #[test]
+    fn generate_with_skipped_fields() {
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            health: u32,
+            #[reflect(@TiledSkip)]
+            target: u32,
+        }
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default)]
+        struct ComponentB(#[reflect(@TiledSkip)] u32, f32);
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+        registry.register::<ComponentB>();
+
+        let exports = TypeExportRegistry::from_registry(&registry);
+        let members = |type_path: &str| match &exports.types.get(type_path).unwrap()[0].type_data {
+            TypeData::Class(class) => class
+                .members
+                .iter()
+                .map(|m| m.name.clone())
+                .collect::<Vec<_>>(),
+            TypeData::Enum(_) => panic!("{type_path} should be a class"),
+        };
+        assert_eq!(members(ComponentA::type_path()), vec!["health"]);
+        assert_eq!(members(ComponentB::type_path()), vec!["1"]);
+
+        let json = serde_json::to_string(&exports.to_vec()).unwrap();
+        assert!(!json.contains("\"target\""));
+    }
+
+    #[test]
+    fn generate_with_vec2() {
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            offset: Vec2,
+            cell: IVec2,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let exports = TypeExportRegistry::from_registry(&registry);
+        let export_type = &exports.types.get(ComponentA::type_path()).unwrap();
+        let TypeData::Class(class) = &export_type[0].type_data else {
+            panic!("ComponentA should be a class");
+        };
+        assert_eq!(class.members[0].type_field, FieldType::Class);
+        assert_eq!(
+            class.members[0].property_type,
+            Some(Vec2::type_path().to_string())
+        );
+        assert_eq!(
+            class.members[0].value,
+            serde_json::json!({"x": 0., "y": 0.})
+        );
+
+        let export_type = &exports.types.get(Vec2::type_path()).unwrap();
+        let TypeData::Class(class) = &export_type[0].type_data else {
+            panic!("Vec2 should be a class");
+        };
+        assert_eq!(
+            class
+                .members
+                .iter()
+                .map(|m| (m.name.as_str(), m.type_field))
+                .collect::<Vec<_>>(),
+            vec![("x", FieldType::Float), ("y", FieldType::Float)]
+        );
+
+        let export_type = &exports.types.get(IVec2::type_path()).unwrap();
+        let TypeData::Class(class) = &export_type[0].type_data else {
+            panic!("IVec2 should be a class");
+        };
+        assert!(class.members.iter().all(|m| m.type_field == FieldType::Int));
+    }
+
+    #[test]
+    fn generate_with_duration() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component, Default)]
+        struct ComponentA(Duration);
+
+        impl Default for ComponentA {
+            fn default() -> Self {
+                Self(Duration::from_millis(1500))
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let exports = TypeExportRegistry::from_registry(&registry);
+        let export_type = &exports.types.get(ComponentA::type_path()).unwrap();
+        let TypeData::Class(class) = &export_type[0].type_data else {
+            panic!("ComponentA should be a class");
+        };
+        assert_eq!(class.members[0].type_field, FieldType::Float);
+        assert_eq!(class.members[0].property_type, None);
+        assert_eq!(class.members[0].value, serde_json::json!(1.5));
+    }
+
+    #[test]
+    fn color_formats() {
+        let color = Color::srgba_u8(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(
+            TiledColorFormat::Legacy.to_tiled(&color),
+            format!("#{:08x}", color.to_linear().as_u32())
+        );
+        assert_eq!(
+            TiledColorFormat::Srgb { alpha: true }.to_tiled(&color),
+            "#44112233"
+        );
+        assert_eq!(
+            TiledColorFormat::Srgb { alpha: false }.to_tiled(&color),
+            "#112233"
+        );
+
+        let color = Color::LinearRgba(LinearRgba::from_u8_array([0x11, 0x22, 0x33, 0x44]));
+        assert_eq!(
+            TiledColorFormat::Linear { alpha: true }.to_tiled(&color),
+            "#44112233"
+        );
+        assert_eq!(
+            TiledColorFormat::Linear { alpha: false }.from_tiled(&tiled::Color {
+                red: 0x11,
+                green: 0x22,
+                blue: 0x33,
+                alpha: 0x44,
+            }),
+            color
+        );
+    }
 }
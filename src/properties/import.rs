@@ -0,0 +1,313 @@
+//! Compare a Tiled types JSON file against Bevy registered types.
+//!
+//! This can be used to detect when the custom types from a Tiled project
+//! have drifted from the types registered in your application.
+//! See also [TiledMapPluginConfig::tiled_project_file](crate::TiledMapPluginConfig::tiled_project_file)
+//! to validate maps against a Tiled project when they are loaded.
+
+use crate::properties::{
+    export::TypeExportRegistry,
+    types_json::{TypeData, TypeExport},
+};
+use bevy::reflect::TypeRegistry;
+use bevy::utils::hashbrown::{HashMap, HashSet};
+use serde::Deserialize;
+use std::{fmt, path::Path};
+use tiled::{LayerType, Properties, PropertyValue};
+
+/// Difference between a Tiled types JSON file and Bevy registered types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeMismatch {
+    /// Type is registered in Bevy but is missing from the Tiled file.
+    MissingType(String),
+    /// Type is in the Tiled file but is not registered in Bevy.
+    UnknownType(String),
+    /// Type is a class on one side and an enum on the other side.
+    KindMismatch(String),
+    /// Class member is missing from the Tiled file.
+    MissingMember {
+        /// Class name
+        type_name: String,
+        /// Member name
+        member: String,
+    },
+    /// Class member is in the Tiled file but does not exist in Bevy.
+    UnknownMember {
+        /// Class name
+        type_name: String,
+        /// Member name
+        member: String,
+    },
+    /// Class member type differs between Tiled and Bevy.
+    MemberTypeMismatch {
+        /// Class name
+        type_name: String,
+        /// Member name
+        member: String,
+    },
+    /// Enum storage type or values differ between Tiled and Bevy.
+    EnumMismatch(String),
+}
+
+impl fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeMismatch::MissingType(name) => write!(f, "type '{name}' is missing from Tiled"),
+            TypeMismatch::UnknownType(name) => write!(f, "type '{name}' is unknown to Bevy"),
+            TypeMismatch::KindMismatch(name) => {
+                write!(f, "type '{name}' is not of the same kind in Tiled and Bevy")
+            }
+            TypeMismatch::MissingMember { type_name, member } => {
+                write!(
+                    f,
+                    "member '{member}' of '{type_name}' is missing from Tiled"
+                )
+            }
+            TypeMismatch::UnknownMember { type_name, member } => {
+                write!(f, "member '{member}' of '{type_name}' is unknown to Bevy")
+            }
+            TypeMismatch::MemberTypeMismatch { type_name, member } => {
+                write!(
+                    f,
+                    "member '{member}' of '{type_name}' does not have the same type"
+                )
+            }
+            TypeMismatch::EnumMismatch(name) => {
+                write!(f, "enum '{name}' does not have the same values")
+            }
+        }
+    }
+}
+
+/// Compare a Tiled types JSON file content against types from a [TypeRegistry].
+///
+/// Only types definition is compared: members default values, colors and IDs are ignored.
+/// Returns an empty [Vec] if both sides are in sync.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::reflect::TypeRegistry;
+/// use bevy_ecs_tiled::properties::import::compare_types_json;
+///
+/// let json = std::fs::read_to_string("propertytypes.json").unwrap();
+/// let registry = TypeRegistry::new();
+/// for mismatch in compare_types_json(&json, &registry).unwrap() {
+///     println!("{mismatch}");
+/// }
+/// ```
+pub fn compare_types_json(
+    json: &str,
+    registry: &TypeRegistry,
+) -> Result<Vec<TypeMismatch>, serde_json::Error> {
+    let imported: Vec<TypeExport> = serde_json::from_str(json)?;
+    let exported = TypeExportRegistry::from_registry(registry).to_vec();
+    Ok(compare_types(&imported, &exported))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProjectFile {
+    Types(Vec<TypeExport>),
+    Project {
+        #[serde(rename = "propertyTypes", default)]
+        property_types: Vec<TypeExport>,
+    },
+}
+
+/// Read types names declared in a `.tiled-project` file or in a Tiled types JSON file.
+pub(crate) fn read_project_types(path: &Path) -> Result<HashSet<String>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let types = match serde_json::from_str(&content).map_err(|e| e.to_string())? {
+        ProjectFile::Project { property_types } => property_types,
+        ProjectFile::Types(types) => types,
+    };
+    Ok(types.into_iter().map(|t| t.name).collect())
+}
+
+/// Warn about classes used in a map which are not declared in the Tiled project
+/// or which are not registered in the [TypeRegistry].
+pub(crate) fn validate_map_types(
+    map: &tiled::Map,
+    map_path: &Path,
+    project_types: &HashSet<String>,
+    registry: &TypeRegistry,
+) {
+    // Classes used as custom properties or as layers class should be registered
+    let mut registered_classes = HashSet::new();
+    // Objects and tiles class are only used in Tiled
+    let mut tiled_classes = HashSet::new();
+
+    collect_classes(&map.properties, &mut registered_classes);
+    let mut to_process = Vec::from_iter(map.layers());
+    while let Some(layer) = to_process.pop() {
+        collect_classes(&layer.properties, &mut registered_classes);
+        registered_classes.extend(layer.user_type.clone());
+        match layer.layer_type() {
+            LayerType::Objects(object_layer) => {
+                for object in object_layer.objects() {
+                    collect_classes(&object.properties, &mut registered_classes);
+                    tiled_classes.insert(object.user_type.clone());
+                }
+            }
+            LayerType::Group(group) => to_process.extend(group.layers()),
+            _ => {}
+        }
+    }
+    for tileset in map.tilesets() {
+        for (_, tile) in tileset.tiles() {
+            collect_classes(&tile.properties, &mut registered_classes);
+            tiled_classes.extend(tile.user_type.clone());
+        }
+    }
+
+    for class in registered_classes.iter().chain(tiled_classes.iter()) {
+        if !class.is_empty() && !project_types.contains(class) {
+            bevy::log::warn!(
+                "Map '{}' uses class '{class}' which is not declared in the Tiled project",
+                map_path.display()
+            );
+        }
+    }
+    for class in registered_classes.iter() {
+        if !class.is_empty() && registry.get_with_type_path(class).is_none() {
+            bevy::log::warn!(
+                "Map '{}' uses class '{class}' which is not registered in the TypeRegistry",
+                map_path.display()
+            );
+        }
+    }
+}
+
+fn collect_classes(properties: &Properties, classes: &mut HashSet<String>) {
+    for property in properties.values() {
+        if let PropertyValue::ClassValue {
+            property_type,
+            properties,
+        } = property
+        {
+            classes.insert(property_type.clone());
+            collect_classes(properties, classes);
+        }
+    }
+}
+
+fn compare_types(imported: &[TypeExport], exported: &[TypeExport]) -> Vec<TypeMismatch> {
+    let imported = imported
+        .iter()
+        .map(|t| (t.name.as_str(), &t.type_data))
+        .collect::<HashMap<_, _>>();
+    let mut out = vec![];
+
+    for export in exported {
+        let name = &export.name;
+        let Some(import) = imported.get(name.as_str()) else {
+            out.push(TypeMismatch::MissingType(name.clone()));
+            continue;
+        };
+        match (&export.type_data, import) {
+            (TypeData::Class(expected), TypeData::Class(actual)) => {
+                for member in &expected.members {
+                    match actual.members.iter().find(|m| m.name == member.name) {
+                        None => out.push(TypeMismatch::MissingMember {
+                            type_name: name.clone(),
+                            member: member.name.clone(),
+                        }),
+                        Some(m)
+                            if m.type_field != member.type_field
+                                || m.property_type != member.property_type =>
+                        {
+                            out.push(TypeMismatch::MemberTypeMismatch {
+                                type_name: name.clone(),
+                                member: member.name.clone(),
+                            })
+                        }
+                        Some(_) => {}
+                    }
+                }
+                for member in &actual.members {
+                    if !expected.members.iter().any(|m| m.name == member.name) {
+                        out.push(TypeMismatch::UnknownMember {
+                            type_name: name.clone(),
+                            member: member.name.clone(),
+                        });
+                    }
+                }
+            }
+            (TypeData::Enum(expected), TypeData::Enum(actual)) => {
+                if expected.storage_type != actual.storage_type
+                    || expected.values != actual.values
+                    || expected.values_as_flags != actual.values_as_flags
+                {
+                    out.push(TypeMismatch::EnumMismatch(name.clone()));
+                }
+            }
+            _ => out.push(TypeMismatch::KindMismatch(name.clone())),
+        }
+    }
+
+    for import in imported.keys() {
+        if !exported.iter().any(|e| e.name == *import) {
+            out.push(TypeMismatch::UnknownType(import.to_string()));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct ComponentA {
+        a: bool,
+        b: f32,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    enum EnumComponent {
+        VarA,
+        VarB,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+        registry.register::<EnumComponent>();
+        registry
+    }
+
+    #[test]
+    fn round_trip() {
+        let registry = registry();
+        let json =
+            serde_json::to_string(&TypeExportRegistry::from_registry(&registry).to_vec()).unwrap();
+        assert_eq!(compare_types_json(&json, &registry).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn drifted() {
+        let registry = registry();
+        let mut exported = TypeExportRegistry::from_registry(&registry).to_vec();
+        for export in exported.iter_mut() {
+            match &mut export.type_data {
+                TypeData::Class(class) => class.members.retain(|m| m.name != "b"),
+                TypeData::Enum(e) => e.values.push("VarC".to_string()),
+            }
+        }
+        let json = serde_json::to_string(&exported).unwrap();
+
+        let mismatches = compare_types_json(&json, &registry).unwrap();
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches.contains(&TypeMismatch::MissingMember {
+            type_name: ComponentA::type_path().to_string(),
+            member: "b".to_string(),
+        }));
+        assert!(mismatches.contains(&TypeMismatch::EnumMismatch(
+            EnumComponent::type_path().to_string()
+        )));
+    }
+}
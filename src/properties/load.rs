@@ -1,4 +1,7 @@
+use super::export::{is_char_array, is_flags, range_item_field};
+use super::{TiledColorFormat, TiledSkip};
 use bevy::asset::LoadContext;
+use bevy::color::ColorToPacked;
 use bevy::ecs::reflect::ReflectBundle;
 use bevy::prelude::*;
 use bevy::reflect::{
@@ -7,10 +10,12 @@ use bevy::reflect::{
     UnnamedField, VariantInfo, VariantType,
 };
 use bevy::utils::HashMap;
+use std::ops::{Range, RangeInclusive};
 use std::path::PathBuf;
+use std::time::Duration;
 use tiled::{LayerType, Properties, PropertyValue, TileId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub(crate) struct DeserializedMapProperties<const HYDRATED: bool = false> {
     pub(crate) map: DeserializedProperties,
     pub(crate) layers: HashMap<u32, DeserializedProperties>,
@@ -22,9 +27,16 @@ impl DeserializedMapProperties<false> {
     pub(crate) fn load(
         map: &tiled::Map,
         registry: &TypeRegistry,
-        load_context: &mut LoadContext<'_>,
+        mut load_context: Option<&mut LoadContext<'_>>,
+        color_format: TiledColorFormat,
     ) -> Self {
-        let map_props = DeserializedProperties::load(&map.properties, registry, load_context, true);
+        let map_props = DeserializedProperties::load(
+            &map.properties,
+            registry,
+            load_context.as_deref_mut(),
+            true,
+            color_format,
+        );
 
         let mut objects = HashMap::new();
         let mut layers = HashMap::new();
@@ -32,7 +44,13 @@ impl DeserializedMapProperties<false> {
         while let Some(layer) = to_process.pop() {
             layers.insert(
                 layer.id(),
-                DeserializedProperties::load(&layer.properties, registry, load_context, false),
+                DeserializedProperties::load(
+                    &layer_properties(&layer, registry),
+                    registry,
+                    load_context.as_deref_mut(),
+                    false,
+                    color_format,
+                ),
             );
             match layer.layer_type() {
                 LayerType::Objects(object) => {
@@ -42,8 +60,9 @@ impl DeserializedMapProperties<false> {
                             DeserializedProperties::load(
                                 &object.properties,
                                 registry,
-                                load_context,
+                                load_context.as_deref_mut(),
                                 false,
+                                color_format,
                             ),
                         );
                     }
@@ -68,8 +87,9 @@ impl DeserializedMapProperties<false> {
                                 DeserializedProperties::load(
                                     &t.properties,
                                     registry,
-                                    load_context,
+                                    load_context.as_deref_mut(),
                                     false,
+                                    color_format,
                                 ),
                             )
                         })
@@ -112,8 +132,39 @@ impl DeserializedMapProperties<false> {
     }
 }
 
+/// Get layer properties, including its class if it is registered in the [TypeRegistry].
+///
+/// Layer class members are stored by Tiled as regular layer properties:
+/// gather them in a class property so they are deserialized as a single [Component].
+fn layer_properties(layer: &tiled::Layer, registry: &TypeRegistry) -> Properties {
+    let mut properties = layer.properties.clone();
+    let Some(class) = layer.user_type.as_ref().filter(|c| !c.is_empty()) else {
+        return properties;
+    };
+    let Some(registration) = registry.get_with_type_path(class) else {
+        bevy::log::debug!("layer class `{class}` is not registered in the TypeRegistry, ignore it");
+        return properties;
+    };
+    let members = match registration.type_info() {
+        TypeInfo::Struct(info) => info
+            .field_names()
+            .iter()
+            .filter_map(|name| properties.remove_entry(*name))
+            .collect(),
+        _ => Properties::default(),
+    };
+    properties.insert(
+        class.clone(),
+        PropertyValue::ClassValue {
+            property_type: class.clone(),
+            properties: members,
+        },
+    );
+    properties
+}
+
 /// Properties for an entity deserialized from a [`Properties`]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct DeserializedProperties {
     pub(crate) properties: Vec<Box<dyn PartialReflect>>,
 }
@@ -127,11 +178,20 @@ impl Clone for DeserializedProperties {
 }
 
 impl DeserializedProperties {
+    /// Iterate over the [TypeId](std::any::TypeId) of all deserialized properties.
+    pub(crate) fn type_ids(&self) -> impl Iterator<Item = std::any::TypeId> + '_ {
+        self.properties
+            .iter()
+            .filter_map(|p| p.get_represented_type_info())
+            .map(|info| info.type_id())
+    }
+
     fn load(
         properties: &tiled::Properties,
         registry: &TypeRegistry,
-        load_cx: &mut LoadContext<'_>,
+        mut load_cx: Option<&mut LoadContext<'_>>,
         resources_allowed: bool,
+        color_format: TiledColorFormat,
     ) -> Self {
         let mut props: Vec<Box<dyn PartialReflect>> = Vec::new();
 
@@ -142,7 +202,12 @@ impl DeserializedProperties {
             } = &property
             else {
                 if let PropertyValue::FileValue(file) = &property {
-                    props.push(Box::new(load_cx.loader().with_unknown_type().load(file)));
+                    match load_cx.as_deref_mut() {
+                        Some(cx) => props.push(Box::new(cx.loader().with_unknown_type().load(file))),
+                        None => bevy::log::warn!(
+                            "error deserializing property: cannot load file `{file}` without a LoadContext"
+                        ),
+                    }
                     continue;
                 }
 
@@ -171,8 +236,11 @@ impl DeserializedProperties {
                 }
             }
 
-            match Self::deserialize_property(property, reg, registry, &mut Some(load_cx), None) {
-                Ok(prop) => {
+            match Self::deserialize_property(property, reg, registry, &mut load_cx, None) {
+                Ok(mut prop) => {
+                    if matches!(color_format, TiledColorFormat::Linear { .. }) {
+                        convert_colors(prop.as_mut(), color_format);
+                    }
                     props.push(prop);
                 }
                 Err(e) => {
@@ -200,8 +268,12 @@ impl DeserializedProperties {
             };
         }
 
+        // Skipped fields always use their default value
         let value;
-        if let Some(pv) = properties.remove(field.name()) {
+        if let Some(pv) = properties
+            .remove(field.name())
+            .filter(|_| !field.custom_attributes().contains::<TiledSkip>())
+        {
             let Some(reg) = registry.get(field.type_id()) else {
                 return Err(format!("type `{}` is not registered", field.type_path()));
             };
@@ -241,8 +313,12 @@ impl DeserializedProperties {
             };
         }
 
+        // Skipped fields always use their default value
         let value;
-        if let Some(pv) = properties.remove(&field.index().to_string()) {
+        if let Some(pv) = properties
+            .remove(&field.index().to_string())
+            .filter(|_| !field.custom_attributes().contains::<TiledSkip>())
+        {
             let Some(reg) = registry.get(field.type_id()) else {
                 return Err(format!("type `{}` is not registered", field.type_path()));
             };
@@ -299,6 +375,15 @@ impl DeserializedProperties {
             ("f32", PV::IntValue(i), _) => Ok(Box::new(i as f32)),
             ("f64", PV::IntValue(i), _) => Ok(Box::new(i as f64)),
 
+            // Durations are either a number of seconds, as exported, or a number of milliseconds
+            // if the property was declared as an integer in Tiled
+            (_, PV::FloatValue(f), info) if info.is::<Duration>() => Duration::try_from_secs_f32(f)
+                .map(|d| Box::new(d) as Box<dyn PartialReflect>)
+                .map_err(|e| format!("invalid duration `{f}`: {e}")),
+            (_, PV::IntValue(i), info) if info.is::<Duration>() => u64::try_from(i)
+                .map(|ms| Box::new(Duration::from_millis(ms)) as Box<dyn PartialReflect>)
+                .map_err(|e| format!("invalid duration `{i}`: {e}")),
+
             ("bevy_color::color::Color", PV::ColorValue(c), _) => {
                 Ok(Box::new(Color::srgba_u8(c.red, c.green, c.blue, c.alpha)))
             }
@@ -340,6 +425,36 @@ impl DeserializedProperties {
 
                 Ok(Box::new(out))
             }
+            (_, PV::IntValue(i), TypeInfo::Struct(info)) if is_flags(registration.type_info()) => {
+                let mut out = DynamicStruct::default();
+                out.set_represented_type(Some(registration.type_info()));
+                for (bit, field) in info.iter().enumerate() {
+                    out.insert(field.name(), (i >> bit) & 1 != 0);
+                }
+                Ok(Box::new(out))
+            }
+            (_, PV::StringValue(s), TypeInfo::Struct(info))
+                if is_flags(registration.type_info()) =>
+            {
+                let flags = s.split(',').map(str::trim).collect::<Vec<_>>();
+                if let Some(flag) = flags
+                    .iter()
+                    .find(|f| !f.is_empty() && info.field(f).is_none())
+                {
+                    return Err(format!("no flag `{}` for `{}`", flag, info.type_path()));
+                }
+                let mut out = DynamicStruct::default();
+                out.set_represented_type(Some(registration.type_info()));
+                for field in info.iter() {
+                    out.insert(field.name(), flags.contains(&field.name()));
+                }
+                Ok(Box::new(out))
+            }
+            (a, PV::ClassValue { mut properties, .. }, TypeInfo::Opaque(_))
+                if range_item_field(a).is_some() =>
+            {
+                deserialize_range(a, &mut properties, default_value)
+            }
             (_, PV::ClassValue { mut properties, .. }, TypeInfo::Struct(info)) => {
                 let mut out = DynamicStruct::default();
                 out.set_represented_type(Some(registration.type_info()));
@@ -421,6 +536,25 @@ impl DeserializedProperties {
 
                 Ok(Box::new(out))
             }
+            (_, PV::StringValue(s), TypeInfo::Array(info))
+                if is_char_array(registration.type_info()) =>
+            {
+                let chars = s
+                    .chars()
+                    .map(|c| Box::new(c) as Box<dyn PartialReflect>)
+                    .collect::<Vec<_>>();
+                if chars.len() != info.capacity() {
+                    return Err(format!(
+                        "expected {} characters for `{}`, got `{}`",
+                        info.capacity(),
+                        info.type_path(),
+                        s
+                    ));
+                }
+                let mut out = DynamicArray::new(chars.into());
+                out.set_represented_type(Some(registration.type_info()));
+                Ok(Box::new(out))
+            }
             (_, PV::ClassValue { mut properties, .. }, TypeInfo::Array(info)) => {
                 let mut array = Vec::new();
 
@@ -431,7 +565,7 @@ impl DeserializedProperties {
                     ));
                 };
 
-                for i in 0..array.capacity() {
+                for i in 0..info.capacity() {
                     let Some(pv) = properties.remove(&format!("[{}]", i)) else {
                         return Err(format!(
                             "missing property on `{}`: `{}`",
@@ -551,6 +685,81 @@ impl DeserializedProperties {
     }
 }
 
+/// Deserialize a Tiled custom property into a reflected value.
+///
+/// This uses the exact same logic as the map loader, which makes it usable from tools
+/// which do not have access to a running [AssetServer], such as editor validators.
+///
+/// Provided [PropertyValue] must be a [PropertyValue::ClassValue] whose `property_type`
+/// is registered in the [TypeRegistry].
+/// If no [LoadContext] is provided, `Handle<T>` fields cannot be deserialized and will return an error.
+/// Note that `Entity` references are not resolved: they contain the raw Tiled object ID.
+pub fn deserialize_property(
+    property: PropertyValue,
+    registry: &TypeRegistry,
+    mut load_context: Option<&mut LoadContext<'_>>,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let PropertyValue::ClassValue { property_type, .. } = &property else {
+        return Err(format!("property `{property:?}` is not a class"));
+    };
+    let Some(registration) = registry.get_with_type_path(property_type) else {
+        return Err(format!(
+            "`{property_type}` is not registered in the TypeRegistry"
+        ));
+    };
+    DeserializedProperties::deserialize_property(
+        property,
+        registration,
+        registry,
+        &mut load_context,
+        None,
+    )
+}
+
+/// Deserialize a `Range<T>` or a `RangeInclusive<T>` of numeric values
+/// from a class with `start` and `end` members.
+fn deserialize_range(
+    type_path: &str,
+    properties: &mut Properties,
+    default_value: Option<&dyn Reflect>,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let mut bound = |name: &str| match properties.remove(name) {
+        Some(PropertyValue::FloatValue(f)) => Ok(Some(f as f64)),
+        Some(PropertyValue::IntValue(i)) => Ok(Some(i as f64)),
+        None => Ok(None),
+        Some(pv) => Err(format!("invalid `{name}` for `{type_path}`: {pv:?}")),
+    };
+    let (start, end) = (bound("start")?, bound("end")?);
+
+    macro_rules! deserialize_range {
+        ($($t:ty),*) => {
+            $(
+                if type_path == Range::<$t>::type_path() {
+                    let default = default_value
+                        .and_then(|d| d.downcast_ref::<Range<$t>>())
+                        .cloned()
+                        .unwrap_or_default();
+                    let start = start.map(|v| v as $t).unwrap_or(default.start);
+                    let end = end.map(|v| v as $t).unwrap_or(default.end);
+                    return Ok(Box::new(start..end));
+                }
+                if type_path == RangeInclusive::<$t>::type_path() {
+                    let default = default_value
+                        .and_then(|d| d.downcast_ref::<RangeInclusive<$t>>())
+                        .cloned()
+                        .unwrap_or(<$t>::default()..=<$t>::default());
+                    let start = start.map(|v| v as $t).unwrap_or(*default.start());
+                    let end = end.map(|v| v as $t).unwrap_or(*default.end());
+                    return Ok(Box::new(start..=end));
+                }
+            )*
+        };
+    }
+    deserialize_range!(f32, f64, isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128);
+
+    Err(format!("unable to deserialize range `{type_path}`"))
+}
+
 fn default_value_from_type_path(registry: &TypeRegistry, path: &str) -> Option<Box<dyn Reflect>> {
     registry
         .get_with_type_path(path)
@@ -583,6 +792,49 @@ fn object_ref(
     }
 }
 
+/// Colors are deserialized as sRGB: convert them according to provided [TiledColorFormat]
+fn convert_colors(object: &mut dyn PartialReflect, color_format: TiledColorFormat) {
+    if let Some(color) = object.try_downcast_mut::<Color>() {
+        let [red, green, blue, alpha] = color.to_srgba().to_u8_array();
+        *color = color_format.from_tiled(&tiled::Color {
+            red,
+            green,
+            blue,
+            alpha,
+        });
+        return;
+    }
+
+    match object.reflect_mut() {
+        ReflectMut::Struct(s) => {
+            for i in 0..s.field_len() {
+                convert_colors(s.field_at_mut(i).unwrap(), color_format);
+            }
+        }
+        ReflectMut::TupleStruct(s) => {
+            for i in 0..s.field_len() {
+                convert_colors(s.field_mut(i).unwrap(), color_format);
+            }
+        }
+        ReflectMut::Tuple(s) => {
+            for i in 0..s.field_len() {
+                convert_colors(s.field_mut(i).unwrap(), color_format);
+            }
+        }
+        ReflectMut::Array(s) => {
+            for i in 0..s.len() {
+                convert_colors(s.get_mut(i).unwrap(), color_format);
+            }
+        }
+        ReflectMut::Enum(s) => {
+            for i in 0..s.field_len() {
+                convert_colors(s.field_at_mut(i).unwrap(), color_format);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn hydrate(object: &mut dyn PartialReflect, obj_entity_map: &HashMap<u32, Entity>) {
     if let Some(obj) = object_ref(object, obj_entity_map) {
         object.apply(obj.as_partial_reflect());
@@ -632,8 +884,10 @@ fn hydrate(object: &mut dyn PartialReflect, obj_entity_map: &HashMap<u32, Entity
         ReflectMut::Map(s) => {
             for i in 0..s.len() {
                 let (k, v) = s.get_at_mut(i).unwrap();
-                if object_ref(k, obj_entity_map).is_some() {
-                    panic!("Unable to hydrate a key in a map!");
+                if k.represents::<Entity>() || k.represents::<Option<Entity>>() {
+                    bevy::log::warn!(
+                        "error hydrating properties: cannot hydrate an Entity used as a map key"
+                    );
                 }
                 hydrate(v, obj_entity_map);
             }
@@ -794,4 +1048,339 @@ mod tests {
         let v: Result<StructComponent, _> = FromReflect::take_from_reflect(res);
         assert_eq!(v.unwrap(), raw_value);
     }
+
+    #[test]
+    fn deserialize_flags() {
+        #[derive(Reflect, Default, PartialEq, Debug)]
+        #[reflect(Default, @crate::properties::TiledFlags)]
+        struct Flags {
+            walkable: bool,
+            flammable: bool,
+            slippery: bool,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Flags>();
+        let registration = registry.get_with_type_path(Flags::type_path()).unwrap();
+
+        let expected = Flags {
+            walkable: true,
+            flammable: false,
+            slippery: true,
+        };
+
+        let res = DeserializedProperties::deserialize_property(
+            PropertyValue::IntValue(0b101),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .unwrap();
+        let v: Result<Flags, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(v.unwrap(), expected);
+
+        let res = DeserializedProperties::deserialize_property(
+            PropertyValue::StringValue("walkable,slippery".to_string()),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .unwrap();
+        let v: Result<Flags, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(v.unwrap(), expected);
+    }
+
+    #[test]
+    fn deserialize_vec() {
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            offset: Vec2,
+            position: Vec3,
+            cell: UVec2,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let tiled_value = PropertyValue::ClassValue {
+            property_type: ComponentA::type_path().to_string(),
+            properties: std::collections::HashMap::from([
+                (
+                    "offset".to_string(),
+                    PropertyValue::ClassValue {
+                        property_type: Vec2::type_path().to_string(),
+                        properties: std::collections::HashMap::from([
+                            ("x".to_string(), PropertyValue::FloatValue(1.)),
+                            ("y".to_string(), PropertyValue::FloatValue(2.)),
+                        ]),
+                    },
+                ),
+                (
+                    "position".to_string(),
+                    PropertyValue::ClassValue {
+                        property_type: Vec3::type_path().to_string(),
+                        // Missing members should use default value
+                        properties: std::collections::HashMap::from([(
+                            "z".to_string(),
+                            PropertyValue::FloatValue(3.),
+                        )]),
+                    },
+                ),
+                (
+                    "cell".to_string(),
+                    PropertyValue::ClassValue {
+                        property_type: UVec2::type_path().to_string(),
+                        properties: std::collections::HashMap::from([
+                            ("x".to_string(), PropertyValue::IntValue(4)),
+                            ("y".to_string(), PropertyValue::IntValue(5)),
+                        ]),
+                    },
+                ),
+            ]),
+        };
+
+        let res = DeserializedProperties::deserialize_property(
+            tiled_value,
+            registry
+                .get_with_type_path(ComponentA::type_path())
+                .unwrap(),
+            &registry,
+            &mut None,
+            None,
+        )
+        .unwrap();
+
+        let v: Result<ComponentA, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(
+            v.unwrap(),
+            ComponentA {
+                offset: Vec2::new(1., 2.),
+                position: Vec3::new(0., 0., 3.),
+                cell: UVec2::new(4, 5),
+            }
+        );
+    }
+
+    #[test]
+    fn hydrate_collections() {
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            waypoints: Vec<Entity>,
+            links: HashMap<String, Entity>,
+            target: Option<Entity>,
+        }
+
+        let mut value = ComponentA {
+            waypoints: vec![Entity::from_raw(1), Entity::from_raw(2)],
+            links: HashMap::from([("door".to_string(), Entity::from_raw(3))]),
+            target: Some(Entity::from_raw(2)),
+        }
+        .clone_value();
+
+        let obj_entity_map = HashMap::from([
+            (1, Entity::from_raw(101)),
+            (2, Entity::from_raw(102)),
+            (3, Entity::from_raw(103)),
+        ]);
+        hydrate(value.as_mut(), &obj_entity_map);
+
+        let v: Result<ComponentA, _> = FromReflect::take_from_reflect(value);
+        assert_eq!(
+            v.unwrap(),
+            ComponentA {
+                waypoints: vec![Entity::from_raw(101), Entity::from_raw(102)],
+                links: HashMap::from([("door".to_string(), Entity::from_raw(103))]),
+                target: Some(Entity::from_raw(102)),
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing entity for object 4")]
+    fn hydrate_missing_object() {
+        let mut value = vec![Entity::from_raw(4)].clone_value();
+        hydrate(value.as_mut(), &HashMap::from([(1, Entity::from_raw(101))]));
+    }
+
+    #[test]
+    fn deserialize_range() {
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            damage: Range<f32>,
+            level: RangeInclusive<u32>,
+        }
+
+        impl Default for ComponentA {
+            fn default() -> Self {
+                Self {
+                    damage: 0.0..1.0,
+                    level: 1..=5,
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let tiled_value = PropertyValue::ClassValue {
+            property_type: ComponentA::type_path().to_string(),
+            properties: std::collections::HashMap::from([
+                (
+                    "damage".to_string(),
+                    PropertyValue::ClassValue {
+                        property_type: Range::<f32>::type_path().to_string(),
+                        properties: std::collections::HashMap::from([
+                            ("start".to_string(), PropertyValue::FloatValue(1.5)),
+                            ("end".to_string(), PropertyValue::FloatValue(4.)),
+                        ]),
+                    },
+                ),
+                (
+                    "level".to_string(),
+                    PropertyValue::ClassValue {
+                        property_type: RangeInclusive::<u32>::type_path().to_string(),
+                        properties: std::collections::HashMap::from([(
+                            "end".to_string(),
+                            PropertyValue::IntValue(10),
+                        )]),
+                    },
+                ),
+            ]),
+        };
+
+        let res = deserialize_property(tiled_value, &registry, None).unwrap();
+        let v: Result<ComponentA, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(
+            v.unwrap(),
+            ComponentA {
+                damage: 1.5..4.,
+                level: 1..=10,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_char_array() {
+        #[derive(Component, Reflect, Default, PartialEq, Debug)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            code: [char; 4],
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        let tiled_value = |code: &str| PropertyValue::ClassValue {
+            property_type: ComponentA::type_path().to_string(),
+            properties: std::collections::HashMap::from([(
+                "code".to_string(),
+                PropertyValue::StringValue(code.to_string()),
+            )]),
+        };
+
+        let res = deserialize_property(tiled_value("ABCD"), &registry, None).unwrap();
+        let v: Result<ComponentA, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(
+            v.unwrap(),
+            ComponentA {
+                code: ['A', 'B', 'C', 'D']
+            }
+        );
+
+        assert!(deserialize_property(tiled_value("ABC"), &registry, None).is_err());
+    }
+
+    #[test]
+    fn deserialize_duration() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Duration>();
+        let registration = registry.get(std::any::TypeId::of::<Duration>()).unwrap();
+
+        let res = DeserializedProperties::deserialize_property(
+            PropertyValue::FloatValue(1.5),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .unwrap();
+        let v: Result<Duration, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(v.unwrap(), Duration::from_millis(1500));
+
+        let res = DeserializedProperties::deserialize_property(
+            PropertyValue::IntValue(250),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .unwrap();
+        let v: Result<Duration, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(v.unwrap(), Duration::from_millis(250));
+
+        assert!(DeserializedProperties::deserialize_property(
+            PropertyValue::IntValue(-250),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .is_err());
+
+        assert!(DeserializedProperties::deserialize_property(
+            PropertyValue::FloatValue(-1.),
+            registration,
+            &registry,
+            &mut None,
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn deserialize_skipped_field() {
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component, Default)]
+        struct ComponentA {
+            health: u32,
+            #[reflect(@TiledSkip)]
+            target: u32,
+        }
+
+        impl Default for ComponentA {
+            fn default() -> Self {
+                Self {
+                    health: 10,
+                    target: 42,
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<ComponentA>();
+
+        // A value set in Tiled for a skipped field is ignored
+        let tiled_value = PropertyValue::ClassValue {
+            property_type: ComponentA::type_path().to_string(),
+            properties: std::collections::HashMap::from([
+                ("health".to_string(), PropertyValue::IntValue(5)),
+                ("target".to_string(), PropertyValue::IntValue(1)),
+            ]),
+        };
+
+        let res = deserialize_property(tiled_value, &registry, None).unwrap();
+        let v: Result<ComponentA, _> = FromReflect::take_from_reflect(res);
+        assert_eq!(
+            v.unwrap(),
+            ComponentA {
+                health: 5,
+                target: 42
+            }
+        );
+    }
 }
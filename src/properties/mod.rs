@@ -6,5 +6,138 @@
 
 pub(crate) mod command;
 pub(crate) mod export;
+pub mod import;
 pub(crate) mod load;
 pub(crate) mod types_json;
+
+pub use load::deserialize_property;
+
+use bevy::{
+    color::{Color, ColorToPacked, LinearRgba},
+    reflect::Reflect,
+};
+
+/// Reflect attribute to handle a struct as a Tiled flags enum.
+///
+/// The struct must only contain `bool` fields: each field is mapped to a flag,
+/// using fields declaration order for bits position (first field is bit 0).
+/// It will be exported as an enum with `values_as_flags` set and stored as an integer.
+///
+/// Note that such a struct can only be used as a field of another type, not directly as a property.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// #[derive(Reflect, Default)]
+/// #[reflect(Default, @TiledFlags)]
+/// struct TileFlags {
+///     walkable: bool,
+///     flammable: bool,
+/// }
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, Default)]
+/// struct TileInfos {
+///     flags: TileFlags,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default)]
+pub struct TiledFlags;
+
+/// Reflect attribute to skip a field when exporting and loading Tiled types.
+///
+/// The field will not appear in Tiled and will always use its default value:
+/// useful for runtime-only fields. The containing type should implement [Default]
+/// (or the field type itself), so this default value can be found.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, Default)]
+/// struct Enemy {
+///     health: u32,
+///     #[reflect(@TiledSkip)]
+///     current_target: Option<Vec2>,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default)]
+pub struct TiledSkip;
+
+/// Reflect attribute setting the color of a class when exporting Tiled types.
+///
+/// Color must use Tiled format, either `#rrggbb` or `#aarrggbb`.
+/// Types without this attribute are exported in black.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, Default, @TiledClassColor("#ff0000"))]
+/// struct Enemy {
+///     health: u32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct TiledClassColor(pub &'static str);
+
+/// Format used for `Color` custom properties.
+///
+/// Controls how a [Color] is written when exporting Tiled types and
+/// how a Tiled color is interpreted when loading a map.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TiledColorFormat {
+    /// Historical behaviour: colors are exported as their raw linear value
+    /// and loaded as sRGB colors.
+    #[default]
+    Legacy,
+    /// Colors are sRGB, both when exporting and loading.
+    Srgb {
+        /// Whether to export the alpha channel (`#AARRGGBB`) or not (`#RRGGBB`).
+        alpha: bool,
+    },
+    /// Colors are linear, both when exporting and loading.
+    Linear {
+        /// Whether to export the alpha channel (`#AARRGGBB`) or not (`#RRGGBB`).
+        alpha: bool,
+    },
+}
+
+impl TiledColorFormat {
+    /// Convert a [Color] to a Tiled color string.
+    pub fn to_tiled(&self, color: &Color) -> String {
+        let ([r, g, b, a], alpha) = match self {
+            TiledColorFormat::Legacy => {
+                return format!("#{:08x}", color.to_linear().as_u32());
+            }
+            TiledColorFormat::Srgb { alpha } => (color.to_srgba().to_u8_array(), alpha),
+            TiledColorFormat::Linear { alpha } => (color.to_linear().to_u8_array(), alpha),
+        };
+        if *alpha {
+            format!("#{a:02x}{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        }
+    }
+
+    /// Convert a Tiled color to a [Color].
+    pub fn from_tiled(&self, color: &tiled::Color) -> Color {
+        match self {
+            TiledColorFormat::Legacy | TiledColorFormat::Srgb { .. } => {
+                Color::srgba_u8(color.red, color.green, color.blue, color.alpha)
+            }
+            TiledColorFormat::Linear { .. } => Color::LinearRgba(LinearRgba::from_u8_array([
+                color.red,
+                color.green,
+                color.blue,
+                color.alpha,
+            ])),
+        }
+    }
+}
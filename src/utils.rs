@@ -1,7 +1,10 @@
 //! This module contains utilities functions.
 use bevy::prelude::*;
-use bevy_ecs_tilemap::prelude::*;
-use tiled::Map;
+use bevy_ecs_tilemap::{
+    helpers::{hex_grid::neighbors::HexNeighbors, square_grid::neighbors::Neighbors},
+    prelude::*,
+};
+use tiled::{Map, ObjectShape};
 
 /// Convert a [tiled::Map]'s [tiled::Orientation] to a [TilemapType]
 pub fn get_map_type(map: &Map) -> TilemapType {
@@ -138,3 +141,225 @@ pub fn from_isometric_coords_to_bevy(
         }
     }
 }
+
+/// Convert from a Bevy position to Tiled isometric coordinates.
+///
+/// This is the inverse of [from_isometric_coords_to_bevy].
+/// Only [IsoCoordSystem::Diamond] maps are supported: position is returned as-is for other maps.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+/// use bevy_ecs_tilemap::prelude::*;
+///
+/// let tiled_position = Vec2::new(8., 24.);
+/// let map_size = TilemapSize::new(4, 4);
+/// let grid_size = TilemapGridSize::new(32., 16.);
+/// let bevy_position = from_isometric_coords_to_bevy(
+///     tiled_position,
+///     &IsoCoordSystem::Diamond,
+///     &map_size,
+///     &grid_size,
+/// );
+/// assert_eq!(
+///     from_bevy_to_isometric_coords(bevy_position, &IsoCoordSystem::Diamond, &map_size, &grid_size),
+///     tiled_position,
+/// );
+/// ```
+pub fn from_bevy_to_isometric_coords(
+    bevy_position: Vec2,
+    iso_coords: &IsoCoordSystem,
+    map_size: &TilemapSize,
+    grid_size: &TilemapGridSize,
+) -> Vec2 {
+    match iso_coords {
+        IsoCoordSystem::Diamond => {
+            // (x - y) and (x + y), expressed in tiles
+            let diff = 2. * bevy_position.x / grid_size.x - map_size.y as f32;
+            let sum = map_size.y as f32 - 1. - 2. * bevy_position.y / grid_size.y;
+            Vec2::new((sum + diff) / 2., (sum - diff) / 2.) * grid_size.y
+        }
+        IsoCoordSystem::Staggered => bevy_position,
+    }
+}
+
+/// Convert from a position relative to an isometric (diamond) map to a [TilePos].
+///
+/// Provided position should be expressed in the map layers referential,
+/// ie. without the map [Transform] and without [LayerPositioning] transform.
+/// Each tile covers the diamond around its center, as spawned by `bevy_ecs_tilemap`.
+/// Returns `None` if the position is outside of the map diamond.
+///
+/// Note that returned [TilePos] uses `bevy_ecs_tilemap` convention, where `y` axis points up.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+/// use bevy_ecs_tilemap::prelude::*;
+///
+/// let map_size = TilemapSize::new(4, 4);
+/// let grid_size = TilemapGridSize::new(32., 16.);
+/// let map_type = TilemapType::Isometric(IsoCoordSystem::Diamond);
+/// // Center of tile (1, 2): tilemaps are offset by half a tile when spawned
+/// let map_position = TilePos::new(1, 2).center_in_world(&grid_size, &map_type)
+///     + Vec2::new(grid_size.x / 2., grid_size.y / 2.);
+/// assert_eq!(
+///     iso_tile_pos_from_map_position(map_position, &map_size, &grid_size),
+///     Some(TilePos::new(1, 2)),
+/// );
+/// assert_eq!(
+///     iso_tile_pos_from_map_position(Vec2::new(-10., 0.), &map_size, &grid_size),
+///     None,
+/// );
+/// ```
+pub fn iso_tile_pos_from_map_position(
+    map_position: Vec2,
+    map_size: &TilemapSize,
+    grid_size: &TilemapGridSize,
+) -> Option<TilePos> {
+    // Tilemaps are offset by half a tile when spawned
+    let tilemap_position = map_position - Vec2::new(grid_size.x / 2., grid_size.y / 2.);
+    // Tile (x, y) center is at ((x + y) * grid_size.x / 2, (y - x) * grid_size.y / 2):
+    // invert this projection and round to the nearest center
+    let normalized = tilemap_position / Vec2::new(grid_size.x, grid_size.y);
+    let (x, y) = (
+        (normalized.x - normalized.y).round(),
+        (normalized.x + normalized.y).round(),
+    );
+    if x < 0. || y < 0. || x >= map_size.x as f32 || y >= map_size.y as f32 {
+        return None;
+    }
+    Some(TilePos::new(x as u32, y as u32))
+}
+
+/// Convert from a position relative to the map to a [TilePos].
+///
+/// Provided position should be expressed in the map layers referential,
+/// ie. without the map [Transform] and without [LayerPositioning] transform.
+/// Returns `None` if the position is outside of the map.
+/// Isometric (diamond) maps use [iso_tile_pos_from_map_position].
+///
+/// Note that returned [TilePos] uses `bevy_ecs_tilemap` convention, where `y` axis points up.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+/// use bevy_ecs_tilemap::prelude::*;
+///
+/// let tile_pos = tile_pos_from_map_position(
+///     Vec2::new(20., 40.),
+///     &TilemapType::Square,
+///     &TilemapSize::new(4, 4),
+///     &TilemapGridSize::new(16., 16.),
+/// );
+/// assert_eq!(tile_pos, Some(TilePos::new(1, 2)));
+/// ```
+pub fn tile_pos_from_map_position(
+    map_position: Vec2,
+    map_type: &TilemapType,
+    map_size: &TilemapSize,
+    grid_size: &TilemapGridSize,
+) -> Option<TilePos> {
+    if matches!(map_type, TilemapType::Isometric(IsoCoordSystem::Diamond)) {
+        return iso_tile_pos_from_map_position(map_position, map_size, grid_size);
+    }
+    // Tilemaps are offset by half a tile when spawned
+    let tilemap_position = map_position - Vec2::new(grid_size.x / 2., grid_size.y / 2.);
+    TilePos::from_world_pos(&tilemap_position, map_size, grid_size, map_type)
+}
+
+/// Get the neighbors of a [TilePos], according to the map type.
+///
+/// For square and isometric maps, you can choose whether you want to include diagonal neighbors.
+/// Hexagonal maps always return their 6 neighbors.
+/// Neighbors outside of the map are not returned.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+/// use bevy_ecs_tilemap::prelude::*;
+///
+/// let neighbors = tile_neighbors(
+///     &TilePos::new(0, 0),
+///     &TilemapType::Square,
+///     &TilemapSize::new(4, 4),
+///     false,
+/// );
+/// assert_eq!(neighbors.count(), 2);
+/// ```
+pub fn tile_neighbors(
+    tile_pos: &TilePos,
+    map_type: &TilemapType,
+    map_size: &TilemapSize,
+    include_diagonals: bool,
+) -> impl Iterator<Item = TilePos> {
+    let neighbors: Vec<TilePos> = match map_type {
+        TilemapType::Hexagon(hex_coord_system) => {
+            HexNeighbors::get_neighboring_positions(tile_pos, map_size, hex_coord_system)
+                .iter()
+                .copied()
+                .collect()
+        }
+        TilemapType::Isometric(IsoCoordSystem::Staggered) => {
+            Neighbors::get_staggered_neighboring_positions(tile_pos, map_size, include_diagonals)
+                .iter()
+                .copied()
+                .collect()
+        }
+        _ => Neighbors::get_square_neighboring_positions(tile_pos, map_size, include_diagonals)
+            .iter()
+            .copied()
+            .collect(),
+    };
+    neighbors.into_iter()
+}
+
+/// Number of vertices used to approximate an ellipse in [object_shape_vertices].
+const ELLIPSE_SEGMENTS: usize = 32;
+
+/// Get the vertices of a Tiled [ObjectShape].
+///
+/// Vertices are relative to the object origin and expressed in Tiled referential,
+/// ie. with the `y` axis pointing down and without the object rotation.
+/// Ellipses are approximated using a polygon.
+/// Only the last vertex of a closed shape is connected to the first one:
+/// for polylines, vertices are returned as is.
+///
+/// Example:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_tiled::prelude::*;
+///
+/// let vertices = object_shape_vertices(&tiled::ObjectShape::Rect {
+///     width: 16.,
+///     height: 8.,
+/// });
+/// assert_eq!(vertices.len(), 4);
+/// ```
+pub fn object_shape_vertices(shape: &ObjectShape) -> Vec<Vec2> {
+    match shape {
+        ObjectShape::Rect { width, height } | ObjectShape::Text { width, height, .. } => vec![
+            Vec2::new(0., 0.),
+            Vec2::new(*width, 0.),
+            Vec2::new(*width, *height),
+            Vec2::new(0., *height),
+        ],
+        ObjectShape::Ellipse { width, height } => {
+            let half_size = Vec2::new(width / 2., height / 2.);
+            (0..ELLIPSE_SEGMENTS)
+                .map(|i| {
+                    let angle = i as f32 * std::f32::consts::TAU / ELLIPSE_SEGMENTS as f32;
+                    half_size + Vec2::new(angle.cos(), angle.sin()) * half_size
+                })
+                .collect()
+        }
+        ObjectShape::Polyline { points } | ObjectShape::Polygon { points } => {
+            points.iter().map(|(x, y)| Vec2::new(*x, *y)).collect()
+        }
+        ObjectShape::Point(_, _) => vec![Vec2::ZERO],
+    }
+}
@@ -0,0 +1,603 @@
+//! This module contains utilities to write a Tiled map back to the `.tmx` format.
+//!
+//! Export is lossy: only data needed to display the map is written, ie. map attributes,
+//! tilesets (with their tiles properties), tile layers, object layers, image layers and group layers.
+//! Notably, tiles animations and collisions, Wang sets, templates and tile objects are not exported.
+//!
+//! Only the immutable [Map] loaded from the asset is written: maps built or edited at runtime
+//! cannot be saved, since changes made to spawned entities, for instance tiles added, removed
+//! or edited through `bevy_ecs_tilemap` or moved objects, are not exported.
+
+use std::{
+    fmt::Write,
+    path::{Component, Path},
+};
+use tiled::{
+    Color, HorizontalAlignment, Layer, LayerTileData, LayerType, Map, ObjectData, ObjectShape,
+    Orientation, Properties, PropertyValue, StaggerAxis, StaggerIndex, TileLayer, Tileset,
+    VerticalAlignment,
+};
+
+const FLIPPED_HORIZONTALLY_FLAG: u32 = 0x80000000;
+const FLIPPED_VERTICALLY_FLAG: u32 = 0x40000000;
+const FLIPPED_DIAGONALLY_FLAG: u32 = 0x20000000;
+
+/// Write a Tiled [Map] to a `.tmx` string.
+///
+/// `map_path` is the path where the map will be saved.
+/// It is used to write paths to images and external tilesets relative to the map,
+/// so it must use the same root as the [Map] paths: for maps loaded through the
+/// `AssetServer`, it should be relative to the assets directory.
+pub fn write_tmx(map: &Map, map_path: &Path) -> String {
+    let map_dir = map_path.parent().unwrap_or(Path::new(""));
+    let mut out = String::new();
+    let _ = writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = write!(
+        out,
+        r#"<map version="1.10" orientation="{}" renderorder="right-down" width="{}" height="{}" tilewidth="{}" tileheight="{}" infinite="{}""#,
+        orientation(map.orientation),
+        map.width,
+        map.height,
+        map.tile_width,
+        map.tile_height,
+        map.infinite() as u8,
+    );
+    if matches!(
+        map.orientation,
+        Orientation::Staggered | Orientation::Hexagonal
+    ) {
+        if let Some(length) = map.hex_side_length {
+            let _ = write!(out, r#" hexsidelength="{length}""#);
+        }
+        let _ = write!(
+            out,
+            r#" staggeraxis="{}" staggerindex="{}""#,
+            match map.stagger_axis {
+                StaggerAxis::X => "x",
+                StaggerAxis::Y => "y",
+            },
+            match map.stagger_index {
+                StaggerIndex::Even => "even",
+                StaggerIndex::Odd => "odd",
+            }
+        );
+    }
+    if let Some(color) = map.background_color {
+        let _ = write!(out, r#" backgroundcolor="{}""#, color_to_string(&color));
+    }
+    let _ = writeln!(out, ">");
+    write_properties(&mut out, &map.properties, 1);
+
+    let first_gids = first_gids(map);
+    for (tileset, first_gid) in map.tilesets().iter().zip(&first_gids) {
+        write_tileset(&mut out, tileset, *first_gid, map_dir, map_path);
+    }
+    for layer in map.layers() {
+        write_layer(&mut out, &layer, &first_gids, map_dir, 1);
+    }
+    let _ = writeln!(out, "</map>");
+    out
+}
+
+// We do not know original first GIDs: compute our own so they do not overlap
+fn first_gids(map: &Map) -> Vec<u32> {
+    let mut next = 1;
+    map.tilesets()
+        .iter()
+        .map(|tileset| {
+            let first_gid = next;
+            let max_id = tileset.tiles().map(|(id, _)| id + 1).max().unwrap_or(0);
+            next += tileset.tilecount.max(max_id);
+            first_gid
+        })
+        .collect()
+}
+
+fn write_tileset(
+    out: &mut String,
+    tileset: &Tileset,
+    first_gid: u32,
+    map_dir: &Path,
+    map_path: &Path,
+) {
+    let is_external =
+        tileset.source.extension().is_some_and(|ext| ext == "tsx") && tileset.source != map_path;
+    if is_external {
+        let _ = writeln!(
+            out,
+            r#" <tileset firstgid="{first_gid}" source="{}"/>"#,
+            escape(&relative_path(&tileset.source, map_dir))
+        );
+        return;
+    }
+    let _ = writeln!(
+        out,
+        r#" <tileset firstgid="{first_gid}" name="{}" tilewidth="{}" tileheight="{}" spacing="{}" margin="{}" tilecount="{}" columns="{}">"#,
+        escape(&tileset.name),
+        tileset.tile_width,
+        tileset.tile_height,
+        tileset.spacing,
+        tileset.margin,
+        tileset.tilecount,
+        tileset.columns,
+    );
+    write_properties(out, &tileset.properties, 2);
+    if let Some(image) = &tileset.image {
+        write_image(out, image, map_dir, 2);
+    }
+    for (id, tile) in tileset.tiles() {
+        if tile.properties.is_empty() && tile.image.is_none() {
+            continue;
+        }
+        let _ = writeln!(out, r#"  <tile id="{id}">"#);
+        write_properties(out, &tile.properties, 3);
+        if let Some(image) = &tile.image {
+            write_image(out, image, map_dir, 3);
+        }
+        let _ = writeln!(out, "  </tile>");
+    }
+    let _ = writeln!(out, " </tileset>");
+}
+
+fn write_image(out: &mut String, image: &tiled::Image, map_dir: &Path, indent: usize) {
+    let _ = writeln!(
+        out,
+        r#"{}<image source="{}" width="{}" height="{}"/>"#,
+        " ".repeat(indent),
+        escape(&relative_path(&image.source, map_dir)),
+        image.width,
+        image.height
+    );
+}
+
+fn write_layer(out: &mut String, layer: &Layer, first_gids: &[u32], map_dir: &Path, indent: usize) {
+    let pad = " ".repeat(indent);
+    let mut attributes = format!(r#"id="{}" name="{}""#, layer.id(), escape(&layer.name));
+    if !layer.visible {
+        attributes.push_str(r#" visible="0""#);
+    }
+    if layer.opacity != 1. {
+        let _ = write!(attributes, r#" opacity="{}""#, layer.opacity);
+    }
+    if layer.offset_x != 0. || layer.offset_y != 0. {
+        let _ = write!(
+            attributes,
+            r#" offsetx="{}" offsety="{}""#,
+            layer.offset_x, layer.offset_y
+        );
+    }
+    if let Some(color) = layer.tint_color {
+        let _ = write!(attributes, r#" tintcolor="{}""#, color_to_string(&color));
+    }
+
+    match layer.layer_type() {
+        LayerType::Tiles(tile_layer) => {
+            let (width, height) = match &tile_layer {
+                TileLayer::Finite(finite) => (finite.width(), finite.height()),
+                TileLayer::Infinite(_) => (0, 0),
+            };
+            let _ = writeln!(
+                out,
+                r#"{pad}<layer {attributes} width="{width}" height="{height}">"#
+            );
+            write_properties(out, &layer.properties, indent + 1);
+            let _ = writeln!(out, r#"{pad} <data encoding="csv">"#);
+            match tile_layer {
+                TileLayer::Finite(finite) => {
+                    write_csv(out, width, height, |x, y| {
+                        gid(finite.get_tile_data(x as i32, y as i32), first_gids)
+                    });
+                }
+                TileLayer::Infinite(infinite) => {
+                    for ((chunk_x, chunk_y), chunk) in infinite.chunks() {
+                        let (chunk_width, chunk_height) =
+                            (tiled::ChunkData::WIDTH, tiled::ChunkData::HEIGHT);
+                        let _ = writeln!(
+                            out,
+                            r#"{pad}  <chunk x="{}" y="{}" width="{chunk_width}" height="{chunk_height}">"#,
+                            chunk_x * chunk_width as i32,
+                            chunk_y * chunk_height as i32,
+                        );
+                        write_csv(out, chunk_width, chunk_height, |x, y| {
+                            gid(chunk.get_tile_data(x as i32, y as i32), first_gids)
+                        });
+                        let _ = writeln!(out, "{pad}  </chunk>");
+                    }
+                }
+            }
+            let _ = writeln!(out, "{pad} </data>");
+            let _ = writeln!(out, "{pad}</layer>");
+        }
+        LayerType::Objects(object_layer) => {
+            let _ = writeln!(out, "{pad}<objectgroup {attributes}>");
+            write_properties(out, &layer.properties, indent + 1);
+            for object in object_layer.objects() {
+                write_object(out, &object, indent + 1);
+            }
+            let _ = writeln!(out, "{pad}</objectgroup>");
+        }
+        LayerType::Image(image_layer) => {
+            let _ = writeln!(out, "{pad}<imagelayer {attributes}>");
+            write_properties(out, &layer.properties, indent + 1);
+            if let Some(image) = &image_layer.image {
+                write_image(out, image, map_dir, indent + 1);
+            }
+            let _ = writeln!(out, "{pad}</imagelayer>");
+        }
+        LayerType::Group(group_layer) => {
+            let _ = writeln!(out, "{pad}<group {attributes}>");
+            write_properties(out, &layer.properties, indent + 1);
+            for layer in group_layer.layers() {
+                write_layer(out, &layer, first_gids, map_dir, indent + 1);
+            }
+            let _ = writeln!(out, "{pad}</group>");
+        }
+    }
+}
+
+fn write_csv(out: &mut String, width: u32, height: u32, gid_at: impl Fn(u32, u32) -> u32) {
+    let rows = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| gid_at(x, y).to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>();
+    let _ = writeln!(out, "{}", rows.join(",\n"));
+}
+
+fn gid(tile: Option<&LayerTileData>, first_gids: &[u32]) -> u32 {
+    let Some(tile) = tile else {
+        return 0;
+    };
+    let mut gid = first_gids[tile.tileset_index()] + tile.id();
+    if tile.flip_h {
+        gid |= FLIPPED_HORIZONTALLY_FLAG;
+    }
+    if tile.flip_v {
+        gid |= FLIPPED_VERTICALLY_FLAG;
+    }
+    if tile.flip_d {
+        gid |= FLIPPED_DIAGONALLY_FLAG;
+    }
+    gid
+}
+
+fn write_object(out: &mut String, object: &ObjectData, indent: usize) {
+    let pad = " ".repeat(indent);
+    let _ = write!(
+        out,
+        r#"{pad}<object id="{}" name="{}""#,
+        object.id(),
+        escape(&object.name)
+    );
+    if !object.user_type.is_empty() {
+        let _ = write!(out, r#" type="{}""#, escape(&object.user_type));
+    }
+    let _ = write!(out, r#" x="{}" y="{}""#, object.x, object.y);
+    match &object.shape {
+        ObjectShape::Rect { width, height }
+        | ObjectShape::Ellipse { width, height }
+        | ObjectShape::Text { width, height, .. } => {
+            let _ = write!(out, r#" width="{width}" height="{height}""#);
+        }
+        _ => {}
+    }
+    if object.rotation != 0. {
+        let _ = write!(out, r#" rotation="{}""#, object.rotation);
+    }
+    if !object.visible {
+        let _ = write!(out, r#" visible="0""#);
+    }
+    let _ = writeln!(out, ">");
+    write_properties(out, &object.properties, indent + 1);
+
+    let pad_inner = " ".repeat(indent + 1);
+    match &object.shape {
+        ObjectShape::Rect { .. } => {}
+        ObjectShape::Ellipse { .. } => {
+            let _ = writeln!(out, "{pad_inner}<ellipse/>");
+        }
+        ObjectShape::Point(_, _) => {
+            let _ = writeln!(out, "{pad_inner}<point/>");
+        }
+        ObjectShape::Polyline { points } => {
+            let _ = writeln!(
+                out,
+                r#"{pad_inner}<polyline points="{}"/>"#,
+                points_to_string(points)
+            );
+        }
+        ObjectShape::Polygon { points } => {
+            let _ = writeln!(
+                out,
+                r#"{pad_inner}<polygon points="{}"/>"#,
+                points_to_string(points)
+            );
+        }
+        ObjectShape::Text {
+            font_family,
+            pixel_size,
+            wrap,
+            color,
+            bold,
+            italic,
+            underline,
+            strikeout,
+            kerning,
+            halign,
+            valign,
+            text,
+            ..
+        } => {
+            let _ = writeln!(
+                out,
+                r#"{pad_inner}<text fontfamily="{}" pixelsize="{pixel_size}" wrap="{}" color="{}" bold="{}" italic="{}" underline="{}" strikeout="{}" kerning="{}" halign="{}" valign="{}">{}</text>"#,
+                escape(font_family),
+                *wrap as u8,
+                color_to_string(color),
+                *bold as u8,
+                *italic as u8,
+                *underline as u8,
+                *strikeout as u8,
+                *kerning as u8,
+                match halign {
+                    HorizontalAlignment::Left => "left",
+                    HorizontalAlignment::Center => "center",
+                    HorizontalAlignment::Right => "right",
+                    HorizontalAlignment::Justify => "justify",
+                },
+                match valign {
+                    VerticalAlignment::Top => "top",
+                    VerticalAlignment::Center => "center",
+                    VerticalAlignment::Bottom => "bottom",
+                },
+                escape(text)
+            );
+        }
+    }
+    let _ = writeln!(out, "{pad}</object>");
+}
+
+fn write_properties(out: &mut String, properties: &Properties, indent: usize) {
+    if properties.is_empty() {
+        return;
+    }
+    let pad = " ".repeat(indent);
+    let _ = writeln!(out, "{pad}<properties>");
+    // Sort properties so output is stable
+    let mut properties = properties.iter().collect::<Vec<_>>();
+    properties.sort_by_key(|(name, _)| name.as_str());
+    for (name, value) in properties {
+        let name = escape(name);
+        let (ty, value) = match value {
+            PropertyValue::BoolValue(v) => ("bool", v.to_string()),
+            PropertyValue::FloatValue(v) => ("float", v.to_string()),
+            PropertyValue::IntValue(v) => ("int", v.to_string()),
+            PropertyValue::ColorValue(v) => ("color", color_to_string(v)),
+            PropertyValue::StringValue(v) => ("string", escape(v)),
+            PropertyValue::FileValue(v) => ("file", escape(v)),
+            PropertyValue::ObjectValue(v) => ("object", v.to_string()),
+            PropertyValue::ClassValue {
+                property_type,
+                properties,
+            } => {
+                let _ = writeln!(
+                    out,
+                    r#"{pad} <property name="{name}" type="class" propertytype="{}">"#,
+                    escape(property_type)
+                );
+                write_properties(out, properties, indent + 2);
+                let _ = writeln!(out, "{pad} </property>");
+                continue;
+            }
+        };
+        let _ = writeln!(
+            out,
+            r#"{pad} <property name="{name}" type="{ty}" value="{value}"/>"#
+        );
+    }
+    let _ = writeln!(out, "{pad}</properties>");
+}
+
+fn orientation(orientation: Orientation) -> &'static str {
+    match orientation {
+        Orientation::Orthogonal => "orthogonal",
+        Orientation::Isometric => "isometric",
+        Orientation::Staggered => "staggered",
+        Orientation::Hexagonal => "hexagonal",
+    }
+}
+
+fn color_to_string(color: &Color) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        color.alpha, color.red, color.green, color.blue
+    )
+}
+
+fn points_to_string(points: &[(f32, f32)]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn relative_path(path: &Path, map_dir: &Path) -> String {
+    // Cannot compute a relative path between an absolute and a relative path
+    if path.has_root() != map_dir.has_root() {
+        return path.to_string_lossy().replace('\\', "/");
+    }
+    let (path, map_dir) = (normalize(path), normalize(map_dir));
+    let common = path
+        .iter()
+        .zip(&map_dir)
+        .take_while(|(a, b)| a == b)
+        .count();
+    vec!["..".to_string(); map_dir.len() - common]
+        .into_iter()
+        .chain(path[common..].iter().cloned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Lexically resolve `.` and `..` path components, without accessing the filesystem
+fn normalize(path: &Path) -> Vec<String> {
+    let mut components: Vec<String> = vec![];
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if components
+                    .last()
+                    .is_some_and(|last| last != ".." && !last.ends_with(['/', '\\', ':'])) =>
+            {
+                components.pop();
+            }
+            component => components.push(component.as_os_str().to_string_lossy().into_owned()),
+        }
+    }
+    components
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashMap, path::PathBuf};
+
+    /// Read files relative to the `assets` directory, like the `AssetServer` does,
+    /// and serve exported maps from memory.
+    #[derive(Default)]
+    struct AssetsReader {
+        exported: HashMap<PathBuf, String>,
+    }
+
+    impl tiled::ResourceReader for AssetsReader {
+        type Resource = Box<dyn std::io::Read>;
+        type Error = std::io::Error;
+
+        fn read_from(&mut self, path: &Path) -> Result<Self::Resource, Self::Error> {
+            let path = PathBuf::from(normalize(path).join("/"));
+            if let Some(content) = self.exported.get(&path) {
+                return Ok(Box::new(std::io::Cursor::new(content.clone().into_bytes())));
+            }
+            Ok(Box::new(std::fs::File::open(
+                Path::new("assets").join(path),
+            )?))
+        }
+    }
+
+    fn load(reader: AssetsReader, path: &str) -> Map {
+        tiled::Loader::with_cache_and_reader(tiled::DefaultResourceCache::new(), reader)
+            .load_tmx_map(path)
+            .unwrap()
+    }
+
+    /// Export provided map to `path` and load it back.
+    fn export(map: &Map, path: &str) -> Map {
+        let content = write_tmx(map, Path::new(path));
+        load(
+            AssetsReader {
+                exported: HashMap::from([(PathBuf::from(path), content)]),
+            },
+            path,
+        )
+    }
+
+    fn assert_same_map(exported: &Map, map: &Map) {
+        assert_eq!(exported.width, map.width);
+        assert_eq!(exported.height, map.height);
+        assert_eq!(exported.tilesets().len(), map.tilesets().len());
+        for (a, b) in exported.tilesets().iter().zip(map.tilesets()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(
+                a.image.as_ref().map(|image| normalize(&image.source)),
+                b.image.as_ref().map(|image| normalize(&image.source))
+            );
+        }
+        assert_eq!(exported.layers().len(), map.layers().len());
+        for (a, b) in exported.layers().zip(map.layers()) {
+            assert_eq!(a.name, b.name);
+            match (a.layer_type(), b.layer_type()) {
+                (
+                    LayerType::Tiles(TileLayer::Finite(a)),
+                    LayerType::Tiles(TileLayer::Finite(b)),
+                ) => {
+                    for x in 0..b.width() as i32 {
+                        for y in 0..b.height() as i32 {
+                            let a = a.get_tile_data(x, y).map(|t| (t.tileset_index(), t.id()));
+                            let b = b.get_tile_data(x, y).map(|t| (t.tileset_index(), t.id()));
+                            assert_eq!(a, b);
+                        }
+                    }
+                }
+                (LayerType::Objects(a), LayerType::Objects(b)) => {
+                    assert_eq!(a.objects().count(), b.objects().count());
+                    for (a, b) in a.objects().zip(b.objects()) {
+                        assert_eq!(a.shape, b.shape);
+                        assert_eq!((a.x, a.y), (b.x, b.y));
+                    }
+                }
+                (LayerType::Image(a), LayerType::Image(b)) => {
+                    assert_eq!(
+                        a.image.as_ref().map(|image| normalize(&image.source)),
+                        b.image.as_ref().map(|image| normalize(&image.source))
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn round_trip(name: &str) {
+        let map = load(AssetsReader::default(), name);
+        assert_same_map(&export(&map, &format!("round_trip_{name}")), &map);
+    }
+
+    #[test]
+    fn round_trip_finite() {
+        round_trip("finite.tmx");
+    }
+
+    #[test]
+    fn round_trip_embedded() {
+        round_trip("finite_embedded.tmx");
+    }
+
+    #[test]
+    fn round_trip_subdirectories() {
+        let map = load(AssetsReader::default(), "finite.tmx");
+        let nested = export(&map, "maps/nested/finite.tmx");
+        assert_same_map(&nested, &map);
+        // Map in a subdirectory, exported to another subdirectory
+        assert!(write_tmx(&nested, Path::new("other/finite.tmx"))
+            .contains(r#"<tileset firstgid="1" source="../Tileset1.tsx"/>"#));
+        assert_same_map(&export(&nested, "other/finite.tmx"), &map);
+        // Map in a subdirectory, exported to the root directory
+        assert_same_map(&export(&nested, "finite.tmx"), &map);
+    }
+
+    #[test]
+    fn relative_paths() {
+        let relative =
+            |path: &str, map_dir: &str| relative_path(Path::new(path), Path::new(map_dir));
+        assert_eq!(relative("tiles/a.png", ""), "tiles/a.png");
+        assert_eq!(relative("tiles/a.png", "maps"), "../tiles/a.png");
+        assert_eq!(
+            relative("maps/../tiles/./a.png", "maps/nested"),
+            "../../tiles/a.png"
+        );
+        assert_eq!(relative("maps/tiles/a.png", "maps"), "tiles/a.png");
+        assert_eq!(relative("/assets/a.png", "/assets/maps"), "../a.png");
+    }
+}